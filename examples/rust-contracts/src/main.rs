@@ -10,7 +10,7 @@
 use glin_sdk::contracts::{
     ArbitrationContract, CreateDisputeParams, VoteChoice, VoteParams,
 };
-use sp_core::{sr25519::Pair, Pair as PairTrait};
+use glin_sdk::signer::Signer;
 use sp_keyring::AccountKeyring;
 use subxt::{OnlineClient, PolkadotConfig};
 
@@ -29,11 +29,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 2: Create accounts
     println!("👤 Creating test accounts...");
-    let alice = AccountKeyring::Alice.pair(); // Claimant
-    let bob = AccountKeyring::Bob.pair(); // Defendant
-    let charlie = AccountKeyring::Charlie.pair(); // Arbitrator 1
-    let dave = AccountKeyring::Dave.pair(); // Arbitrator 2
-    let eve = AccountKeyring::Eve.pair(); // Arbitrator 3
+    let alice: Signer = AccountKeyring::Alice.pair().into(); // Claimant
+    let charlie: Signer = AccountKeyring::Charlie.pair().into(); // Arbitrator 1
+    let dave: Signer = AccountKeyring::Dave.pair().into(); // Arbitrator 2
+    let eve: Signer = AccountKeyring::Eve.pair().into(); // Arbitrator 3
 
     println!("Claimant (Alice): {:?}", AccountKeyring::Alice.to_account_id());
     println!("Defendant (Bob): {:?}", AccountKeyring::Bob.to_account_id());
@@ -62,7 +61,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Register Charlie as arbitrator
     let result1 = contract
-        .register_arbitrator(200_000_000_000_000_000_000, &charlie)
+        .register_arbitrator(200_000_000_000_000_000_000, &charlie, None)
         .await?;
     if result1.success {
         println!("✅ Charlie registered as arbitrator (stake: 200 GLIN)");
@@ -72,7 +71,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Register Dave as arbitrator
     let result2 = contract
-        .register_arbitrator(300_000_000_000_000_000_000, &dave)
+        .register_arbitrator(300_000_000_000_000_000_000, &dave, None)
         .await?;
     if result2.success {
         println!("✅ Dave registered as arbitrator (stake: 300 GLIN)");
@@ -82,7 +81,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Register Eve as arbitrator
     let result3 = contract
-        .register_arbitrator(150_000_000_000_000_000_000, &eve)
+        .register_arbitrator(150_000_000_000_000_000_000, &eve, None)
         .await?;
     if result3.success {
         println!("✅ Eve registered as arbitrator (stake: 150 GLIN)");
@@ -114,9 +113,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         defendant: AccountKeyring::Bob.to_account_id(),
         description: "Provider failed to deliver agreed AI training services despite full payment of 5000 GLIN".to_string(),
         evidence_uri: "ipfs://QmXYZ.../training-dispute-evidence.pdf".to_string(),
+        initial_evidence: None,
     };
 
-    let dispute_result = contract.create_dispute(dispute_params, &alice).await?;
+    let dispute_result = contract.create_dispute(dispute_params, &alice, None).await?;
     if dispute_result.success {
         println!("✅ Dispute created! ID: 0");
     } else {
@@ -141,7 +141,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 8: Start voting
     println!("🗳️  Starting voting period...");
-    let start_result = contract.start_voting(dispute_id, &alice).await?;
+    let start_result = contract.start_voting(dispute_id, &alice, None).await?;
     if start_result.success {
         println!("✅ Voting period started (duration: 7 days)");
     } else {
@@ -157,7 +157,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         dispute_id,
         choice: VoteChoice::InFavorOfClaimant,
     };
-    let vote_result1 = contract.vote(vote1, &charlie).await?;
+    let vote_result1 = contract.vote(vote1, &charlie, None).await?;
     if vote_result1.success {
         println!("✅ Charlie voted: In favor of claimant (200 GLIN)");
     }
@@ -167,7 +167,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         dispute_id,
         choice: VoteChoice::InFavorOfDefendant,
     };
-    let vote_result2 = contract.vote(vote2, &dave).await?;
+    let vote_result2 = contract.vote(vote2, &dave, None).await?;
     if vote_result2.success {
         println!("✅ Dave voted: In favor of defendant (300 GLIN)");
     }
@@ -177,7 +177,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         dispute_id,
         choice: VoteChoice::InFavorOfClaimant,
     };
-    let vote_result3 = contract.vote(vote3, &eve).await?;
+    let vote_result3 = contract.vote(vote3, &eve, None).await?;
     if vote_result3.success {
         println!("✅ Eve voted: In favor of claimant (150 GLIN)");
     }
@@ -211,7 +211,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!();
 
     println!("🏁 Finalizing dispute...");
-    let finalize_result = contract.finalize_dispute(dispute_id, &alice).await?;
+    let finalize_result = contract.finalize_dispute(dispute_id, &alice, None).await?;
     if finalize_result.success {
         if let Some(resolution) = finalize_result.data {
             match resolution {
@@ -1,10 +1,150 @@
-//! Authentication and signature verification
+//! Authentication: structured sign-in messages and sr25519 signature verification
+//!
+//! The previous free-form message (just an app name and a timestamp) let a
+//! signature captured for one domain be replayed against another, and didn't
+//! bind to a specific signer or expire on its own. [`GlinAuth::generate_auth_message`]
+//! now emits a message with one field per line — `domain`, `address`, `nonce`,
+//! `issued_at`, `expiration_time` — that [`GlinAuth::parse_auth_message`] can
+//! validate, and [`GlinAuth::verify_signature`] checks all of it before doing
+//! the actual sr25519 verification.
+
+use crate::signer::{verify_against_address, Signature};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+
+/// Parameters for [`GlinAuth::generate_auth_message`]
+#[derive(Debug, Clone)]
+pub struct AuthMessageParams {
+    /// The dApp's origin, e.g. `"app.glin.ai"`, checked by `verify_signature`
+    /// to reject a message signed for a different site
+    pub domain: String,
+    pub app_name: String,
+    /// SS58 address of the account expected to sign this message
+    pub address: String,
+    /// Caller-generated random token, binding this message to a single
+    /// sign-in attempt so a captured signature can't be reused
+    pub nonce: String,
+    pub issued_at: DateTime<Utc>,
+    pub expiration_time: DateTime<Utc>,
+}
+
+/// A [`GlinAuth::generate_auth_message`] message, parsed back out of its
+/// line-oriented wire format by [`GlinAuth::parse_auth_message`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedAuthMessage {
+    pub domain: String,
+    pub app_name: String,
+    pub address: String,
+    pub nonce: String,
+    pub issued_at: DateTime<Utc>,
+    pub expiration_time: DateTime<Utc>,
+}
+
+/// Outcome of [`GlinAuth::verify_signature`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthResult {
+    /// The message is well-formed, unexpired, for the right domain, and the
+    /// signature matches `address`
+    Valid,
+    /// The signature doesn't match `address` over the message bytes
+    BadSignature,
+    /// `expiration_time` has passed
+    Expired,
+    /// The message's `domain` field doesn't match the domain the caller expected
+    DomainMismatch { expected: String, found: String },
+    /// The message's `address` field doesn't match the `address` the caller
+    /// is verifying against — the message was validly signed, but not by (or
+    /// for) the account the caller thinks it was
+    AddressMismatch { expected: String, found: String },
+    /// The message isn't in the format `generate_auth_message` produces
+    MalformedMessage(String),
+}
+
+impl AuthResult {
+    pub fn is_valid(&self) -> bool {
+        matches!(self, AuthResult::Valid)
+    }
+}
 
 /// Authentication utilities
 pub struct GlinAuth;
 
 impl GlinAuth {
-    /// Verify a signature
+    /// Build a structured, replay-resistant sign-in message
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glin_sdk::auth::{AuthMessageParams, GlinAuth};
+    /// use chrono::{Duration, Utc};
+    ///
+    /// let now = Utc::now();
+    /// let message = GlinAuth::generate_auth_message(&AuthMessageParams {
+    ///     domain: "app.glin.ai".to_string(),
+    ///     app_name: "MyApp".to_string(),
+    ///     address: "5GrwvaEF...".to_string(),
+    ///     nonce: "a1b2c3d4".to_string(),
+    ///     issued_at: now,
+    ///     expiration_time: now + Duration::minutes(5),
+    /// });
+    /// println!("{}", message);
+    /// ```
+    pub fn generate_auth_message(params: &AuthMessageParams) -> String {
+        format!(
+            "Sign in to {app_name}\n\n\
+             Domain: {domain}\n\
+             Address: {address}\n\
+             Nonce: {nonce}\n\
+             Issued At: {issued_at}\n\
+             Expiration Time: {expiration_time}\n\n\
+             This signature will not trigger a blockchain transaction or cost any fees.",
+            app_name = params.app_name,
+            domain = params.domain,
+            address = params.address,
+            nonce = params.nonce,
+            issued_at = params.issued_at.to_rfc3339(),
+            expiration_time = params.expiration_time.to_rfc3339(),
+        )
+    }
+
+    /// Parse a message produced by [`generate_auth_message`](Self::generate_auth_message)
+    /// back into its fields, without checking expiration or domain
+    pub fn parse_auth_message(message: &str) -> Result<ParsedAuthMessage> {
+        let app_name = message
+            .lines()
+            .next()
+            .and_then(|line| line.strip_prefix("Sign in to "))
+            .ok_or_else(|| anyhow!("message missing 'Sign in to <app>' header"))?
+            .to_string();
+
+        let field = |label: &str| -> Option<String> {
+            let prefix = format!("{label}: ");
+            message.lines().find_map(|line| line.strip_prefix(prefix.as_str()).map(str::to_string))
+        };
+        let parse_timestamp = |label: &str| -> Result<DateTime<Utc>> {
+            let value = field(label).ok_or_else(|| anyhow!("message missing '{label}' field"))?;
+            DateTime::parse_from_rfc3339(&value)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|error| anyhow!("invalid '{label}' timestamp '{value}': {error}"))
+        };
+
+        Ok(ParsedAuthMessage {
+            domain: field("Domain").ok_or_else(|| anyhow!("message missing 'Domain' field"))?,
+            app_name,
+            address: field("Address").ok_or_else(|| anyhow!("message missing 'Address' field"))?,
+            nonce: field("Nonce").ok_or_else(|| anyhow!("message missing 'Nonce' field"))?,
+            issued_at: parse_timestamp("Issued At")?,
+            expiration_time: parse_timestamp("Expiration Time")?,
+        })
+    }
+
+    /// Verify an sr25519 sign-in signature over a structured auth message
+    ///
+    /// Checks, in order: the message parses, its `domain` field matches
+    /// `expected_domain`, its `address` field matches `address`,
+    /// `expiration_time` hasn't passed, and `signature` (hex-encoded, with or
+    /// without a `0x` prefix) verifies against `address`'s sr25519 public key
+    /// over the raw message bytes.
     ///
     /// # Example
     ///
@@ -15,37 +155,156 @@ impl GlinAuth {
     /// let message = "Sign in to MyApp...";
     /// let signature = "0x...";
     ///
-    /// let is_valid = GlinAuth::verify_signature(address, message, signature);
-    /// if is_valid {
+    /// let result = GlinAuth::verify_signature(address, message, signature, "app.glin.ai");
+    /// if result.is_valid() {
     ///     println!("Signature is valid!");
     /// }
     /// ```
     pub fn verify_signature(
-        _address: &str,
-        _message: &str,
-        _signature: &str,
-    ) -> bool {
-        // TODO: Implement proper sr25519 signature verification
-        // This is a placeholder implementation
-        false
+        address: &str,
+        message: &str,
+        signature: &str,
+        expected_domain: &str,
+    ) -> AuthResult {
+        let parsed = match Self::parse_auth_message(message) {
+            Ok(parsed) => parsed,
+            Err(error) => return AuthResult::MalformedMessage(error.to_string()),
+        };
+
+        if parsed.domain != expected_domain {
+            return AuthResult::DomainMismatch {
+                expected: expected_domain.to_string(),
+                found: parsed.domain,
+            };
+        }
+        if parsed.address != address {
+            return AuthResult::AddressMismatch {
+                expected: address.to_string(),
+                found: parsed.address,
+            };
+        }
+        if Utc::now() > parsed.expiration_time {
+            return AuthResult::Expired;
+        }
+
+        let Some(signature_bytes) = decode_hex_64(signature) else {
+            return AuthResult::BadSignature;
+        };
+        let signature = Signature::Sr25519(sp_core::sr25519::Signature::from_raw(signature_bytes));
+
+        match verify_against_address(&signature, message.as_bytes(), address) {
+            Ok(true) => AuthResult::Valid,
+            _ => AuthResult::BadSignature,
+        }
     }
+}
 
-    /// Generate authentication message
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use glin_sdk::GlinAuth;
-    ///
-    /// let message = GlinAuth::generate_auth_message("MyApp");
-    /// println!("{}", message);
-    /// ```
-    pub fn generate_auth_message(app_name: &str) -> String {
-        let timestamp = chrono::Utc::now().to_rfc3339();
-        format!(
-            "Sign in to {}\n\nTimestamp: {}\n\nThis signature will not trigger a blockchain transaction or cost any fees.",
-            app_name,
-            timestamp
-        )
+/// Decode a hex-encoded (optionally `0x`-prefixed) 64-byte sr25519 signature
+fn decode_hex_64(hex: &str) -> Option<[u8; 64]> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() != 128 {
+        return None;
+    }
+
+    let mut bytes = [0u8; 64];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::{Scheme, Signer};
+    use chrono::Duration;
+
+    fn signed_message(
+        signer: &Signer,
+        domain: &str,
+        address: &str,
+        expiration_time: DateTime<Utc>,
+    ) -> (String, String) {
+        let message = GlinAuth::generate_auth_message(&AuthMessageParams {
+            domain: domain.to_string(),
+            app_name: "TestApp".to_string(),
+            address: address.to_string(),
+            nonce: "nonce".to_string(),
+            issued_at: Utc::now(),
+            expiration_time,
+        });
+        let signature_hex = match signer.sign(message.as_bytes()) {
+            crate::signer::Signature::Sr25519(signature) => {
+                signature.0.iter().map(|b| format!("{b:02x}")).collect()
+            }
+            _ => panic!("test signer is always sr25519"),
+        };
+        (message, signature_hex)
+    }
+
+    #[test]
+    fn test_verify_signature_valid() {
+        let (signer, _) = Signer::generate(Scheme::Sr25519);
+        let address = signer.to_ss58check();
+        let (message, signature) =
+            signed_message(&signer, "app.glin.ai", &address, Utc::now() + Duration::minutes(5));
+
+        let result = GlinAuth::verify_signature(&address, &message, &signature, "app.glin.ai");
+        assert_eq!(result, AuthResult::Valid);
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn test_verify_signature_rejects_expired_message() {
+        let (signer, _) = Signer::generate(Scheme::Sr25519);
+        let address = signer.to_ss58check();
+        let (message, signature) =
+            signed_message(&signer, "app.glin.ai", &address, Utc::now() - Duration::minutes(1));
+
+        let result = GlinAuth::verify_signature(&address, &message, &signature, "app.glin.ai");
+        assert_eq!(result, AuthResult::Expired);
+    }
+
+    #[test]
+    fn test_verify_signature_rejects_domain_mismatch() {
+        let (signer, _) = Signer::generate(Scheme::Sr25519);
+        let address = signer.to_ss58check();
+        let (message, signature) =
+            signed_message(&signer, "app.glin.ai", &address, Utc::now() + Duration::minutes(5));
+
+        let result = GlinAuth::verify_signature(&address, &message, &signature, "other.example");
+        assert_eq!(
+            result,
+            AuthResult::DomainMismatch {
+                expected: "other.example".to_string(),
+                found: "app.glin.ai".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_signature_rejects_address_mismatch() {
+        let (signer, _) = Signer::generate(Scheme::Sr25519);
+        let (other, _) = Signer::generate(Scheme::Sr25519);
+        let signed_address = signer.to_ss58check();
+        let caller_address = other.to_ss58check();
+        let (message, signature) = signed_message(
+            &signer,
+            "app.glin.ai",
+            &signed_address,
+            Utc::now() + Duration::minutes(5),
+        );
+
+        // The message is validly signed, but embeds a different address than
+        // the one the caller is checking against.
+        let result =
+            GlinAuth::verify_signature(&caller_address, &message, &signature, "app.glin.ai");
+        assert_eq!(
+            result,
+            AuthResult::AddressMismatch {
+                expected: caller_address,
+                found: signed_address,
+            }
+        );
     }
 }
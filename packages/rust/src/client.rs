@@ -1,13 +1,26 @@
 //! Blockchain client for GLIN network
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use scale_value::Value;
+use sp_core::crypto::{AccountId32, Ss58Codec};
 use subxt::{OnlineClient, PolkadotConfig};
-use crate::types::Balance;
+use crate::retry::{ConnectionState, FailureKind, RetryPolicy};
+use crate::types::{Balance, BlockHash};
+use std::future::Future;
+use std::sync::{Mutex, RwLock};
+
+type StateChangeCallback = Box<dyn Fn(ConnectionState) + Send + Sync>;
 
 /// GLIN blockchain client
+///
+/// Wraps a `subxt` [`OnlineClient`] with automatic reconnection: a dropped
+/// WebSocket or a transient RPC error triggers exponential backoff and a
+/// fresh connection instead of poisoning the client for its whole lifetime.
 pub struct GlinClient {
-    client: OnlineClient<PolkadotConfig>,
+    client: RwLock<OnlineClient<PolkadotConfig>>,
     rpc_url: String,
+    retry_policy: RetryPolicy,
+    listeners: Mutex<Vec<StateChangeCallback>>,
 }
 
 impl GlinClient {
@@ -28,10 +41,92 @@ impl GlinClient {
         let rpc_url = rpc_url.into();
         let client = OnlineClient::<PolkadotConfig>::from_url(&rpc_url).await?;
 
-        Ok(Self { client, rpc_url })
+        Ok(Self {
+            client: RwLock::new(client),
+            rpc_url,
+            retry_policy: RetryPolicy::default(),
+            listeners: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Create a new GLIN client with a custom reconnect/retry policy
+    pub async fn with_retry_policy(
+        rpc_url: impl Into<String>,
+        retry_policy: RetryPolicy,
+    ) -> Result<Self> {
+        let mut client = Self::new(rpc_url).await?;
+        client.retry_policy = retry_policy;
+        Ok(client)
+    }
+
+    /// Register a callback invoked whenever the connection state changes
+    /// (e.g. to log reconnects or update a health dashboard)
+    pub fn on_connection_state_change<F>(&self, callback: F)
+    where
+        F: Fn(ConnectionState) + Send + Sync + 'static,
+    {
+        self.listeners.lock().unwrap().push(Box::new(callback));
     }
 
-    /// Get account balance
+    fn notify(&self, state: ConnectionState) {
+        for listener in self.listeners.lock().unwrap().iter() {
+            listener(state);
+        }
+    }
+
+    /// Tear down and re-establish the underlying connection, retrying with
+    /// exponential backoff per the client's [`RetryPolicy`]
+    async fn reconnect(&self) -> Result<()> {
+        self.notify(ConnectionState::Reconnecting);
+
+        let mut attempt = 0;
+        loop {
+            match OnlineClient::<PolkadotConfig>::from_url(&self.rpc_url).await {
+                Ok(new_client) => {
+                    *self.client.write().unwrap() = new_client;
+                    self.notify(ConnectionState::Connected);
+                    return Ok(());
+                }
+                Err(error) => {
+                    attempt += 1;
+                    if attempt >= self.retry_policy.max_attempts {
+                        self.notify(ConnectionState::Disconnected);
+                        return Err(error.into());
+                    }
+                    tokio::time::sleep(self.retry_policy.delay_for_attempt(attempt)).await;
+                }
+            }
+        }
+    }
+
+    /// Run `op` against the current connection, transparently reconnecting
+    /// and retrying on transient failures, and replaying the call afterwards
+    async fn with_retry<T, F, Fut>(&self, mut op: F) -> Result<T>
+    where
+        F: FnMut(OnlineClient<PolkadotConfig>) -> Fut,
+        Fut: Future<Output = Result<T, subxt::Error>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let client = self.client.read().unwrap().clone();
+            match op(client).await {
+                Ok(value) => return Ok(value),
+                Err(error) => match self.retry_policy.classify(&error) {
+                    FailureKind::Permanent => return Err(error.into()),
+                    FailureKind::Transient => {
+                        attempt += 1;
+                        if attempt >= self.retry_policy.max_attempts {
+                            return Err(error.into());
+                        }
+                        tokio::time::sleep(self.retry_policy.delay_for_attempt(attempt)).await;
+                        self.reconnect().await?;
+                    }
+                },
+            }
+        }
+    }
+
+    /// Get account balance at the latest block
     ///
     /// # Example
     ///
@@ -45,24 +140,57 @@ impl GlinClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_balance(&self, _address: &str) -> Result<Balance> {
-        // Note: This is a simplified implementation
-        // In production, use proper subxt storage queries with generated metadata
-
-        // For now, return a mock balance
-        // TODO: Implement actual balance query using subxt
-        Ok(Balance {
-            free: 0,
-            reserved: 0,
-            frozen: 0,
-            total: 0,
+    pub async fn get_balance(&self, address: &str) -> Result<Balance> {
+        self.get_balance_at(address, None).await
+    }
+
+    /// Get account balance as of `at_block`, or the latest block if `None`
+    pub async fn get_balance_at(&self, address: &str, at_block: Option<BlockHash>) -> Result<Balance> {
+        let account = AccountId32::from_ss58check(address)
+            .map_err(|error| anyhow!("'{address}' is not a valid SS58 address: {error:?}"))?;
+
+        self.with_retry(|client| {
+            let account = account.clone();
+            async move {
+                let storage_address =
+                    subxt::dynamic::storage("System", "Account", vec![Value::from_bytes(account.as_ref())]);
+
+                let storage = match at_block {
+                    Some(hash) => client.storage().at(hash),
+                    None => client.storage().at_latest().await?,
+                };
+
+                let Some(entry) = storage.fetch(&storage_address).await? else {
+                    return Ok(Balance { free: 0, reserved: 0, frozen: 0, total: 0 });
+                };
+
+                let value = entry.to_value()?;
+                let data = value
+                    .at("data")
+                    .ok_or_else(|| subxt::Error::Other("unexpected shape for System::Account storage entry".into()))?;
+
+                let field = |name: &str| {
+                    data.at(name)
+                        .and_then(|v| v.as_u128())
+                        .ok_or_else(|| subxt::Error::Other(format!("System::Account data missing '{name}'")))
+                };
+                let free = field("free")?;
+                let reserved = field("reserved")?;
+                let frozen = field("frozen")?;
+
+                Ok(Balance { free, reserved, frozen, total: free + reserved })
+            }
         })
+        .await
     }
 
     /// Get current block number
     pub async fn get_block_number(&self) -> Result<u32> {
-        let header = self.client.blocks().at_latest().await?;
-        Ok(header.number())
+        self.with_retry(|client| async move {
+            let header = client.blocks().at_latest().await?;
+            Ok(header.number())
+        })
+        .await
     }
 
     /// Get the RPC URL
@@ -71,7 +199,10 @@ impl GlinClient {
     }
 
     /// Get the underlying subxt client for advanced usage
-    pub fn subxt_client(&self) -> &OnlineClient<PolkadotConfig> {
-        &self.client
+    ///
+    /// Returns a clone of the currently active connection; after a
+    /// reconnect, call this again to pick up the fresh client.
+    pub fn subxt_client(&self) -> OnlineClient<PolkadotConfig> {
+        self.client.read().unwrap().clone()
     }
 }
@@ -1,8 +1,11 @@
 //! ArbitrationDAO contract client
 
+use super::gas::{self, ContractCall, GasEstimate};
+use super::metadata::ContractMetadata;
 use super::types::*;
-use anyhow::Result;
-use sp_core::sr25519::Pair;
+use anyhow::{anyhow, Result};
+use crate::signer::Signer;
+use scale_value::Value;
 use subxt::{OnlineClient, PolkadotConfig};
 
 /// Client for interacting with ArbitrationDAO smart contract
@@ -16,55 +19,43 @@ use subxt::{OnlineClient, PolkadotConfig};
 /// use glin_sdk::contracts::{ArbitrationContract, CreateDisputeParams};
 ///
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-/// let client = subxt::OnlineClient::new().await?;
+/// let client = subxt::OnlineClient::<subxt::PolkadotConfig>::new().await?;
 /// let arbitration = ArbitrationContract::new(client, "5Arbitration...".parse()?);
 ///
 /// let params = CreateDisputeParams {
 ///     defendant: "5Defendant...".parse()?,
 ///     description: "Contract not fulfilled".to_string(),
 ///     evidence_uri: "ipfs://evidence".to_string(),
+///     initial_evidence: None,
 /// };
 ///
 /// // let result = arbitration.create_dispute(params, &keypair).await?;
 /// # Ok(())
 /// # }
 /// ```
-pub struct ArbitrationContract {
-    client: OnlineClient<PolkadotConfig>,
-    contract_address: AccountId,
-}
-
-impl ArbitrationContract {
-    /// Create a new arbitration contract client
-    pub fn new(client: OnlineClient<PolkadotConfig>, contract_address: AccountId) -> Self {
-        Self {
-            client,
-            contract_address,
-        }
-    }
-
-    /// Update contract address
-    pub fn set_contract_address(&mut self, address: AccountId) {
-        self.contract_address = address;
-    }
+crate::contract_client_scaffold!(ArbitrationContract);
 
+impl<C: subxt::Config> ArbitrationContract<C>
+where
+    crate::signer::Signer: subxt::tx::Signer<C>,
+{
     /// Register as an arbitrator
     ///
     /// # Arguments
     ///
     /// * `stake_amount` - Stake amount
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     pub async fn register_arbitrator(
         &self,
         stake_amount: Balance,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<()>> {
-        // In production, this would:
-        // 1. Encode the contract call using metadata
-        // 2. Create a Contracts::call extrinsic with value = stake_amount
-        // 3. Sign and submit the transaction
-
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let result = self
+            .submit_call("register_arbitrator", &[], stake_amount, signer, gas_limit)
+            .await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Increase arbitrator stake
@@ -73,20 +64,32 @@ impl ArbitrationContract {
     ///
     /// * `additional_stake` - Additional stake amount
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     pub async fn increase_arbitrator_stake(
         &self,
         additional_stake: Balance,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<()>> {
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let result = self
+            .submit_call("increase_arbitrator_stake", &[], additional_stake, signer, gas_limit)
+            .await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Create a new dispute
     ///
+    /// If `params.initial_evidence` is set, its genesis entry is appended via
+    /// [`append_evidence`](Self::append_evidence) right after the dispute is
+    /// created; a failure there is reported back as an error even though the
+    /// dispute itself was already created on-chain, so callers should check
+    /// `get_dispute` before retrying to avoid creating a duplicate.
+    ///
     /// # Arguments
     ///
     /// * `params` - Dispute parameters
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     ///
     /// # Returns
     ///
@@ -94,10 +97,43 @@ impl ArbitrationContract {
     pub async fn create_dispute(
         &self,
         params: CreateDisputeParams,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<u128>> {
-        // In production, this would parse events to extract dispute_id
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let args = [
+            Value::from_bytes(params.defendant.as_ref()),
+            Value::string(params.description),
+            Value::string(params.evidence_uri),
+        ];
+
+        let result = self.submit_call("create_dispute", &args, 0, signer, gas_limit).await?;
+        if !result.success {
+            return Ok(ContractResult::err(result.error.unwrap_or_default()));
+        }
+
+        // Assumes the contract emits a `DisputeCreated` event carrying the
+        // new dispute's id in a `dispute_id` field.
+        let Some(event) = result.data.as_ref().and_then(|events| events.first()) else {
+            return Ok(ContractResult::err("no ContractEmitted event for create_dispute"));
+        };
+        let Some(dispute_id) = event.field("dispute_id").and_then(|v| v.as_u128()) else {
+            return Ok(ContractResult::err(format!(
+                "'{}' event missing u128 'dispute_id' field",
+                event.label
+            )));
+        };
+
+        if let Some(entry) = params.initial_evidence {
+            let evidence_result = self.append_evidence(dispute_id, entry, signer, gas_limit).await?;
+            if !evidence_result.success {
+                return Ok(ContractResult::err(format!(
+                    "dispute {dispute_id} created, but appending initial evidence failed: {}",
+                    evidence_result.error.unwrap_or_default()
+                )));
+            }
+        }
+
+        Ok(ContractResult::ok(dispute_id))
     }
 
     /// Start voting period for a dispute
@@ -106,12 +142,16 @@ impl ArbitrationContract {
     ///
     /// * `dispute_id` - Dispute ID
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     pub async fn start_voting(
         &self,
         dispute_id: u128,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<()>> {
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let args = [Value::u128(dispute_id)];
+        let result = self.submit_call("start_voting", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Cast a vote on a dispute
@@ -120,8 +160,26 @@ impl ArbitrationContract {
     ///
     /// * `params` - Vote parameters
     /// * `signer` - Keypair for signing the transaction
-    pub async fn vote(&self, params: VoteParams, signer: &Pair) -> Result<ContractResult<()>> {
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+    /// * `gas_limit` - Optional explicit gas override for this call
+    pub async fn vote(
+        &self,
+        params: VoteParams,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
+    ) -> Result<ContractResult<()>> {
+        // Encoded as the fieldless `VoteChoice` variant `encode_call` expects;
+        // the registry's type id drives the actual on-chain enum encoding.
+        let choice_variant = match params.choice {
+            VoteChoice::InFavorOfClaimant => "InFavorOfClaimant",
+            VoteChoice::InFavorOfDefendant => "InFavorOfDefendant",
+        };
+        let args = [
+            Value::u128(params.dispute_id),
+            Value::unnamed_variant(choice_variant, vec![]),
+        ];
+
+        let result = self.submit_call("vote", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Finalize a dispute after voting period
@@ -130,6 +188,7 @@ impl ArbitrationContract {
     ///
     /// * `dispute_id` - Dispute ID
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     ///
     /// # Returns
     ///
@@ -137,10 +196,40 @@ impl ArbitrationContract {
     pub async fn finalize_dispute(
         &self,
         dispute_id: u128,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<VoteChoice>> {
-        // In production, this would parse events to extract resolution
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let args = [Value::u128(dispute_id)];
+        let result = self.submit_call("finalize_dispute", &args, 0, signer, gas_limit).await?;
+        if !result.success {
+            return Ok(ContractResult::err(result.error.unwrap_or_default()));
+        }
+
+        // Assumes the contract emits a `DisputeFinalized` event carrying the
+        // resolution as a `resolution` field of the `VoteChoice` enum type.
+        let Some(event) = result.data.as_ref().and_then(|events| events.first()) else {
+            return Ok(ContractResult::err("no ContractEmitted event for finalize_dispute"));
+        };
+        let Some(resolution_field) = event.field("resolution") else {
+            return Ok(ContractResult::err(format!(
+                "'{}' event missing 'resolution' field",
+                event.label
+            )));
+        };
+        let resolution = match &resolution_field.value {
+            scale_value::ValueDef::Variant(variant) if variant.name == "InFavorOfDefendant" => {
+                VoteChoice::InFavorOfDefendant
+            }
+            scale_value::ValueDef::Variant(_) => VoteChoice::InFavorOfClaimant,
+            _ => {
+                return Ok(ContractResult::err(format!(
+                    "'{}' event's 'resolution' field is not an enum variant",
+                    event.label
+                )))
+            }
+        };
+
+        Ok(ContractResult::ok(resolution))
     }
 
     /// Appeal a dispute decision
@@ -149,12 +238,67 @@ impl ArbitrationContract {
     ///
     /// * `dispute_id` - Dispute ID
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     pub async fn appeal_dispute(
         &self,
         dispute_id: u128,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<()>> {
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let args = [Value::u128(dispute_id)];
+        let result = self.submit_call("appeal_dispute", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
+    }
+
+    /// Append an entry to a dispute's evidence hashchain
+    ///
+    /// # Arguments
+    ///
+    /// * `dispute_id` - Dispute ID
+    /// * `entry` - Evidence entry to append; its `prev_hash` must link to the
+    ///   current tip of the on-chain chain
+    /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
+    pub async fn append_evidence(
+        &self,
+        dispute_id: u128,
+        entry: EvidenceEntry,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
+    ) -> Result<ContractResult<()>> {
+        let args = [
+            Value::u128(dispute_id),
+            Value::named_composite(vec![
+                ("uri".into(), Value::string(entry.uri)),
+                ("content_hash".into(), Value::from_bytes(entry.content_hash)),
+                ("prev_hash".into(), Value::from_bytes(entry.prev_hash)),
+                ("submitter".into(), Value::from_bytes(entry.submitter.as_ref())),
+                ("timestamp".into(), Value::u128(entry.timestamp as u128)),
+            ]),
+        ];
+
+        let result = self.submit_call("append_evidence", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
+    }
+
+    /// Verify a dispute's evidence chain is unbroken
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(None)` if the chain is intact, `Ok(Some(index))` with the
+    /// index of the first entry whose `prev_hash` doesn't match the recomputed
+    /// hash of the entry before it (so an altered entry `i` is reported as
+    /// `i + 1`), or an error if the dispute doesn't exist.
+    pub async fn verify_evidence_chain(&self, dispute_id: u128) -> Result<Option<usize>> {
+        let dispute = self
+            .get_dispute(dispute_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("dispute {dispute_id} not found"))?;
+
+        match dispute.evidence_chain.verify() {
+            Ok(()) => Ok(None),
+            Err(index) => Ok(Some(index)),
+        }
     }
 
     /// Get dispute details
@@ -167,8 +311,12 @@ impl ArbitrationContract {
     ///
     /// Returns the dispute or None if not found
     pub async fn get_dispute(&self, dispute_id: u128) -> Result<Option<Dispute>> {
-        // In production, this would query contract storage
-        Ok(None)
+        self.query(
+            "get_dispute",
+            &[Value::u128(dispute_id)],
+            self.contract_address.clone(),
+        )
+        .await
     }
 
     /// Get arbitrator information
@@ -181,8 +329,12 @@ impl ArbitrationContract {
     ///
     /// Returns the arbitrator or None if not found
     pub async fn get_arbitrator(&self, account: &AccountId) -> Result<Option<Arbitrator>> {
-        // In production, this would query contract storage
-        Ok(None)
+        self.query(
+            "get_arbitrator",
+            &[Value::from_bytes(account.as_ref())],
+            self.contract_address.clone(),
+        )
+        .await
     }
 
     /// Get vote for a specific arbitrator on a dispute
@@ -200,8 +352,12 @@ impl ArbitrationContract {
         dispute_id: u128,
         arbitrator: &AccountId,
     ) -> Result<Option<VoteChoice>> {
-        // In production, this would query contract storage
-        Ok(None)
+        self.query(
+            "get_vote",
+            &[Value::u128(dispute_id), Value::from_bytes(arbitrator.as_ref())],
+            self.contract_address.clone(),
+        )
+        .await
     }
 
     /// Check if account is an active arbitrator
@@ -214,8 +370,7 @@ impl ArbitrationContract {
     ///
     /// Returns true if active arbitrator, false otherwise
     pub async fn is_active_arbitrator(&self, account: &AccountId) -> Result<bool> {
-        // In production, this would query contract storage
-        Ok(false)
+        Ok(self.get_arbitrator(account).await?.is_some_and(|a| a.is_active))
     }
 
     /// Calculate voting results for a dispute (convenience method)
@@ -0,0 +1,329 @@
+//! Codegen for the repeated "contract client" scaffold
+//!
+//! `EscrowContract` and `ArbitrationContract` each hand-wrote the same
+//! metadata-loading, gas-resolution, and `Contracts::call` submit/dry-run
+//! plumbing. [`contract_client_scaffold!`] factors that out so a new
+//! contract client only has to declare its own typed per-message methods
+//! on top of it, instead of re-deriving `submit_call`/`query` from scratch.
+//!
+//! It also generates [`subscribe_events`](Self::subscribe_events), streaming
+//! this contract's `ContractEmitted` events decoded against its
+//! `#[ink(event)]` definitions (see [`super::metadata::ContractMetadata::decode_event`]).
+//!
+//! A fuller generator — deriving each message's Rust parameter types
+//! straight from the metadata's scale-info type registry and baking selectors
+//! in at compile time — would need a proc-macro or build-script crate that
+//! parses the metadata JSON ahead of compilation. That doesn't fit how this
+//! SDK loads metadata today, either: a contract's metadata bundle is supplied
+//! at runtime via [`set_metadata`](Self::set_metadata), keyed to whichever
+//! address the caller deploys to, not to a fixed JSON file checked into the
+//! workspace a build script could read ahead of time. Baking selectors and
+//! parameter types in at compile time would mean compiling one `ContractMetadata`
+//! per deployed contract, a bigger change than this scaffold covers on its
+//! own, and this workspace also has no `Cargo.toml`/build-script pipeline to
+//! host such a generator in yet. Until that design question is settled with
+//! whoever owns this request, this macro takes the scaffold as far as a
+//! declarative macro reasonably can: every contract client still loads its
+//! [`super::metadata::ContractMetadata`] at runtime (selectors resolved by
+//! message label, not embedded as compile-time constants), and still
+//! declares its own per-message methods by hand. What moves into the macro
+//! is everything downstream of "I have a message name and some `Value`
+//! arguments" — encoding, dry-running, submitting, and decoding both return
+//! values and emitted events.
+//!
+//! The generated struct is generic over `C: subxt::Config`, defaulting to
+//! [`PolkadotConfig`] so existing call sites are unaffected, but accepting
+//! any chain config (and, via [`new`](Self::new), any pre-built
+//! `OnlineClient<C>` — including one wired up over a mocked or offline
+//! `RpcClient` for tests) without forking the module per-chain.
+#[macro_export]
+macro_rules! contract_client_scaffold {
+    ($name:ident) => {
+        /// Fields of a `pallet_contracts::Event::ContractEmitted` event,
+        /// decoded straight off `EventDetails::field_bytes` without needing
+        /// its metadata type
+        #[derive(parity_scale_codec::Decode)]
+        struct ContractEmittedFields {
+            contract: AccountId,
+            data: Vec<u8>,
+        }
+
+        pub struct $name<C: subxt::Config = PolkadotConfig> {
+            client: OnlineClient<C>,
+            contract_address: AccountId,
+            gas_mode: GasMode,
+            metadata: Option<ContractMetadata>,
+        }
+
+        impl<C: subxt::Config> $name<C> {
+            /// Create a new contract client from a pre-built `OnlineClient<C>`
+            /// (e.g. one connected over a mocked `RpcClient` for tests)
+            pub fn new(client: OnlineClient<C>, contract_address: AccountId) -> Self {
+                Self {
+                    client,
+                    contract_address,
+                    gas_mode: GasMode::default(),
+                    metadata: None,
+                }
+            }
+
+            /// Update contract address
+            pub fn set_contract_address(&mut self, address: AccountId) {
+                self.contract_address = address;
+            }
+
+            /// Set the gas mode used when no per-call `gas_limit` override is given
+            pub fn set_gas_mode(&mut self, gas_mode: GasMode) {
+                self.gas_mode = gas_mode;
+            }
+
+            /// Load the ink! contract metadata used to encode calls and decode
+            /// returns; until this is set, mutating methods fall back to
+            /// `ContractResult::err` and `get_*` methods return `None` (or, for
+            /// getters that don't return an `Option`, their zero/empty default)
+            pub fn set_metadata(&mut self, metadata: ContractMetadata) {
+                self.metadata = Some(metadata);
+            }
+
+            /// Dry-run `call` against the live chain, returning the simulated
+            /// gas/storage cost and return data without submitting anything
+            pub async fn estimate_gas(&self, call: ContractCall) -> Result<GasEstimate> {
+                gas::estimate_gas(&self.client, call).await
+            }
+
+            /// Resolve the gas limit to submit with: the per-call override or
+            /// client-level fixed mode if set, otherwise a dry-run estimate against
+            /// the call's real encoded `input_data`, padded with the client's
+            /// [`GasMode::EstimateWithSafetyMargin`] headroom if configured
+            async fn resolve_gas_limit(
+                &self,
+                signer: &crate::signer::Signer,
+                value: Balance,
+                gas_limit: Option<GasLimit>,
+                input_data: Vec<u8>,
+            ) -> Result<GasLimit> {
+                if let Some(limit) = self.gas_mode.resolve(gas_limit) {
+                    return Ok(limit);
+                }
+
+                let estimate = self
+                    .estimate_gas(ContractCall {
+                        caller: signer.contract_account_id(),
+                        contract: self.contract_address.clone(),
+                        value,
+                        input_data,
+                    })
+                    .await?;
+                Ok(match self.gas_mode.safety_margin_percent() {
+                    Some(percent) => estimate.with_safety_margin(percent),
+                    None => estimate.gas_limit,
+                })
+            }
+
+            /// Encode `msg(args)`, dry-run it for a gas estimate (unless a
+            /// per-call override or fixed gas mode already supplies the limit),
+            /// sign and submit it as a `Contracts::call` extrinsic, and decode this contract's
+            /// `ContractEmitted` events from the finalized block against its
+            /// `#[ink(event)]` definitions
+            ///
+            /// Returns `ContractResult::err` (without touching the chain) if no
+            /// metadata has been loaded via [`set_metadata`](Self::set_metadata).
+            async fn submit_call(
+                &self,
+                msg: &str,
+                args: &[scale_value::Value<u32>],
+                value: Balance,
+                signer: &crate::signer::Signer,
+                gas_limit: Option<GasLimit>,
+            ) -> Result<ContractResult<Vec<super::metadata::DecodedEvent>>>
+            where
+                crate::signer::Signer: subxt::tx::Signer<C>,
+            {
+                let Some(metadata) = &self.metadata else {
+                    return Ok(ContractResult::err("Not implemented - requires contract metadata"));
+                };
+
+                let input_data = metadata.encode_call(msg, args)?;
+                let resolved_gas_limit = self
+                    .resolve_gas_limit(signer, value, gas_limit, input_data.clone())
+                    .await?;
+
+                let tx = subxt::dynamic::tx(
+                    "Contracts",
+                    "call",
+                    vec![
+                        scale_value::Value::unnamed_variant(
+                            "Id",
+                            vec![scale_value::Value::from_bytes(self.contract_address.as_ref())],
+                        ),
+                        scale_value::Value::u128(value),
+                        scale_value::Value::named_composite(vec![
+                            ("ref_time".into(), scale_value::Value::u128(resolved_gas_limit.ref_time as u128)),
+                            ("proof_size".into(), scale_value::Value::u128(resolved_gas_limit.proof_size as u128)),
+                        ]),
+                        match resolved_gas_limit.storage_deposit_limit {
+                            Some(limit) => scale_value::Value::unnamed_variant("Some", vec![scale_value::Value::u128(limit)]),
+                            None => scale_value::Value::unnamed_variant("None", vec![]),
+                        },
+                        scale_value::Value::from_bytes(input_data),
+                    ],
+                );
+
+                let events = self
+                    .client
+                    .tx()
+                    .sign_and_submit_then_watch_default(&tx, signer)
+                    .await?
+                    .wait_for_finalized_success()
+                    .await?;
+
+                let mut emitted = Vec::new();
+                for event in events.iter() {
+                    let event = event?;
+                    if event.pallet_name() == "Contracts" && event.variant_name() == "ContractEmitted" {
+                        let fields = <ContractEmittedFields as parity_scale_codec::Decode>::decode(&mut event.field_bytes())?;
+                        if fields.contract == self.contract_address {
+                            emitted.push(metadata.decode_event(&fields.data)?);
+                        }
+                    }
+                }
+
+                Ok(ContractResult::ok(emitted))
+            }
+
+            /// Stream this contract's `ContractEmitted` events as new blocks
+            /// finalize, decoded against its `#[ink(event)]` definitions
+            ///
+            /// Returns an error (without touching the chain) if no metadata
+            /// has been loaded via [`set_metadata`](Self::set_metadata).
+            pub fn subscribe_events(
+                &self,
+            ) -> Result<impl futures_util::Stream<Item = Result<super::metadata::DecodedEvent>>> {
+                let Some(metadata) = self.metadata.clone() else {
+                    return Err(anyhow::anyhow!("Not implemented - requires contract metadata"));
+                };
+                let contract_address = self.contract_address.clone();
+                let client = self.client.clone();
+
+                Ok(futures_util::stream::unfold(
+                    (client, None, std::collections::VecDeque::new()),
+                    move |(client, blocks, mut pending)| {
+                        let metadata = metadata.clone();
+                        let contract_address = contract_address.clone();
+                        async move {
+                            loop {
+                                if let Some(event) = pending.pop_front() {
+                                    return Some((Ok(event), (client, blocks, pending)));
+                                }
+
+                                let mut blocks = match blocks {
+                                    Some(blocks) => blocks,
+                                    None => match client.blocks().subscribe_finalized().await {
+                                        Ok(blocks) => blocks,
+                                        Err(error) => return Some((Err(error.into()), (client, None, pending))),
+                                    },
+                                };
+
+                                let block = match futures_util::StreamExt::next(&mut blocks).await {
+                                    Some(Ok(block)) => block,
+                                    Some(Err(error)) => {
+                                        return Some((Err(error.into()), (client, Some(blocks), pending)))
+                                    }
+                                    None => return None,
+                                };
+
+                                let events = match block.events().await {
+                                    Ok(events) => events,
+                                    Err(error) => return Some((Err(error.into()), (client, Some(blocks), pending))),
+                                };
+
+                                for event in events.iter() {
+                                    let event = match event {
+                                        Ok(event) => event,
+                                        Err(error) => {
+                                            return Some((Err(error.into()), (client, Some(blocks), pending)))
+                                        }
+                                    };
+                                    if event.pallet_name() == "Contracts" && event.variant_name() == "ContractEmitted" {
+                                        let Ok(fields) =
+                                            <ContractEmittedFields as parity_scale_codec::Decode>::decode(
+                                                &mut event.field_bytes(),
+                                            )
+                                        else {
+                                            continue;
+                                        };
+                                        if fields.contract == contract_address {
+                                            if let Ok(decoded) = metadata.decode_event(&fields.data) {
+                                                pending.push_back(decoded);
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                ))
+            }
+
+            /// Dry-run the read-only message `msg(args)` and decode its return
+            /// value directly into `T`
+            ///
+            /// Returns `Ok(None)` without touching the chain if no metadata has
+            /// been loaded via [`set_metadata`](Self::set_metadata).
+            async fn query<T: parity_scale_codec::Decode>(
+                &self,
+                msg: &str,
+                args: &[scale_value::Value<u32>],
+                caller: AccountId,
+            ) -> Result<Option<T>> {
+                let Some(metadata) = &self.metadata else {
+                    return Ok(None);
+                };
+
+                let input_data = metadata.encode_call(msg, args)?;
+                let estimate = gas::estimate_gas(
+                    &self.client,
+                    ContractCall {
+                        caller,
+                        contract: self.contract_address.clone(),
+                        value: 0,
+                        input_data,
+                    },
+                )
+                .await?;
+
+                // The ink! getter is assumed to return `Option<T>` itself.
+                Ok(<Option<T> as parity_scale_codec::Decode>::decode(&mut &estimate.return_data[..])?)
+            }
+
+            /// Like [`query`](Self::query), but for getters that return `T`
+            /// directly rather than `Option<T>`; returns `default` without
+            /// touching the chain if no metadata has been loaded
+            async fn query_value<T: parity_scale_codec::Decode>(
+                &self,
+                msg: &str,
+                args: &[scale_value::Value<u32>],
+                caller: AccountId,
+                default: T,
+            ) -> Result<T> {
+                let Some(metadata) = &self.metadata else {
+                    return Ok(default);
+                };
+
+                let input_data = metadata.encode_call(msg, args)?;
+                let estimate = gas::estimate_gas(
+                    &self.client,
+                    ContractCall {
+                        caller,
+                        contract: self.contract_address.clone(),
+                        value: 0,
+                        input_data,
+                    },
+                )
+                .await?;
+
+                Ok(<T as parity_scale_codec::Decode>::decode(&mut &estimate.return_data[..])?)
+            }
+        }
+    };
+}
@@ -1,10 +1,15 @@
 //! GenericEscrow contract client
 
+use super::gas::{self, ContractCall, GasEstimate};
+use super::metadata::ContractMetadata;
 use super::types::*;
 use anyhow::Result;
-use sp_core::sr25519::Pair;
+use crate::signer::Signer;
+use scale_value::Value;
 use subxt::{OnlineClient, PolkadotConfig};
 
+pub mod simulate;
+
 /// Client for interacting with GenericEscrow smart contract
 ///
 /// Provides methods to interact with the GenericEscrow contract
@@ -17,7 +22,7 @@ use subxt::{OnlineClient, PolkadotConfig};
 /// use sp_core::crypto::AccountId32;
 ///
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-/// let client = subxt::OnlineClient::new().await?;
+/// let client = subxt::OnlineClient::<subxt::PolkadotConfig>::new().await?;
 /// let escrow = EscrowContract::new(client, "5Escrow...".parse()?);
 ///
 /// let params = CreateAgreementParams {
@@ -28,37 +33,28 @@ use subxt::{OnlineClient, PolkadotConfig};
 ///     dispute_timeout: 1234567890,
 ///     oracle: None,
 ///     value: 2_000_000_000_000_000_000_000,
+///     token_address: None,
+///     milestone_conditions: vec![None, None],
 /// };
 ///
 /// // let result = escrow.create_agreement(params, &keypair).await?;
 /// # Ok(())
 /// # }
 /// ```
-pub struct EscrowContract {
-    client: OnlineClient<PolkadotConfig>,
-    contract_address: AccountId,
-}
-
-impl EscrowContract {
-    /// Create a new escrow contract client
-    pub fn new(client: OnlineClient<PolkadotConfig>, contract_address: AccountId) -> Self {
-        Self {
-            client,
-            contract_address,
-        }
-    }
-
-    /// Update contract address
-    pub fn set_contract_address(&mut self, address: AccountId) {
-        self.contract_address = address;
-    }
+crate::contract_client_scaffold!(EscrowContract);
 
+impl<C: subxt::Config> EscrowContract<C>
+where
+    crate::signer::Signer: subxt::tx::Signer<C>,
+{
     /// Create a new escrow agreement
     ///
     /// # Arguments
     ///
     /// * `params` - Agreement parameters
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call; falls back
+    ///   to the client's [`GasMode`] when `None`
     ///
     /// # Returns
     ///
@@ -66,16 +62,91 @@ impl EscrowContract {
     pub async fn create_agreement(
         &self,
         params: CreateAgreementParams,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<u128>> {
-        // In production, this would:
-        // 1. Encode the contract call using metadata
-        // 2. Create a Contracts::call extrinsic
-        // 3. Sign and submit the transaction
-        // 4. Parse events to extract agreement_id
-
-        // Placeholder implementation
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        if params.milestone_conditions.iter().any(Option::is_some) {
+            // `submit_call` only knows how to build primitive-typed `Value`
+            // arguments; encoding a whole `ReleaseCondition` tree against the
+            // contract's registry type needs value construction support that
+            // isn't built yet, so agreements with custom per-milestone
+            // conditions can't be created through this path yet.
+            return Ok(ContractResult::err(
+                "Not implemented - per-milestone release conditions require contract metadata",
+            ));
+        }
+
+        let native_value = if let Some(token_address) = params.token_address.clone() {
+            // PSP22-denominated agreement: approve the escrow contract to pull
+            // `value` from the client with `transfer_from` itself, instead of
+            // attaching native `value` to the `Contracts::call`.
+            let token = super::token::Psp22Contract::new(self.client.clone(), token_address);
+            let approval = token
+                .approve(&self.contract_address, params.value, signer, gas_limit)
+                .await?;
+            if !approval.success {
+                return Ok(ContractResult::err(format!(
+                    "token approval failed: {}",
+                    approval.error.unwrap_or_default()
+                )));
+            }
+            0
+        } else {
+            params.value
+        };
+
+        let args = [
+            Value::from_bytes(params.provider.as_ref()),
+            Value::unnamed_composite(
+                params
+                    .milestone_descriptions
+                    .into_iter()
+                    .map(Value::string)
+                    .collect(),
+            ),
+            Value::unnamed_composite(
+                params.milestone_amounts.iter().map(|amount| Value::u128(*amount)).collect(),
+            ),
+            Value::unnamed_composite(
+                params
+                    .milestone_deadlines
+                    .iter()
+                    .map(|deadline| Value::u128(*deadline as u128))
+                    .collect(),
+            ),
+            Value::u128(params.dispute_timeout as u128),
+            match &params.oracle {
+                Some(oracle) => Value::unnamed_variant("Some", vec![Value::from_bytes(oracle.as_ref())]),
+                None => Value::unnamed_variant("None", vec![]),
+            },
+            match &params.token_address {
+                Some(token_address) => {
+                    Value::unnamed_variant("Some", vec![Value::from_bytes(token_address.as_ref())])
+                }
+                None => Value::unnamed_variant("None", vec![]),
+            },
+        ];
+
+        let result = self
+            .submit_call("create_agreement", &args, native_value, signer, gas_limit)
+            .await?;
+        if !result.success {
+            return Ok(ContractResult::err(result.error.unwrap_or_default()));
+        }
+
+        // Assumes the contract emits an `AgreementCreated` event carrying the
+        // new agreement's id in an `agreement_id` field.
+        let Some(event) = result.data.as_ref().and_then(|events| events.first()) else {
+            return Ok(ContractResult::err("no ContractEmitted event for create_agreement"));
+        };
+        let Some(agreement_id) = event.field("agreement_id").and_then(|v| v.as_u128()) else {
+            return Ok(ContractResult::err(format!(
+                "'{}' event missing u128 'agreement_id' field",
+                event.label
+            )));
+        };
+
+        Ok(ContractResult::ok(agreement_id))
     }
 
     /// Mark a milestone as completed (by provider)
@@ -85,13 +156,17 @@ impl EscrowContract {
     /// * `agreement_id` - Agreement ID
     /// * `milestone_index` - Index of the milestone
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     pub async fn complete_milestone(
         &self,
         agreement_id: u128,
         milestone_index: u32,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<()>> {
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let args = [Value::u128(agreement_id), Value::u128(milestone_index as u128)];
+        let result = self.submit_call("complete_milestone", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Approve milestone and release funds (by client or oracle)
@@ -101,13 +176,17 @@ impl EscrowContract {
     /// * `agreement_id` - Agreement ID
     /// * `milestone_index` - Index of the milestone
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     pub async fn approve_and_release(
         &self,
         agreement_id: u128,
         milestone_index: u32,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<()>> {
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let args = [Value::u128(agreement_id), Value::u128(milestone_index as u128)];
+        let result = self.submit_call("approve_and_release", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Raise a dispute for a milestone
@@ -117,13 +196,17 @@ impl EscrowContract {
     /// * `agreement_id` - Agreement ID
     /// * `milestone_index` - Index of the milestone
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     pub async fn raise_dispute(
         &self,
         agreement_id: u128,
         milestone_index: u32,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<()>> {
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let args = [Value::u128(agreement_id), Value::u128(milestone_index as u128)];
+        let result = self.submit_call("raise_dispute", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Resolve a dispute (by oracle or after timeout)
@@ -134,14 +217,22 @@ impl EscrowContract {
     /// * `milestone_index` - Index of the milestone
     /// * `release_to_provider` - Whether to release funds to provider
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     pub async fn resolve_dispute(
         &self,
         agreement_id: u128,
         milestone_index: u32,
         release_to_provider: bool,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<()>> {
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let args = [
+            Value::u128(agreement_id),
+            Value::u128(milestone_index as u128),
+            Value::bool(release_to_provider),
+        ];
+        let result = self.submit_call("resolve_dispute", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Get agreement details
@@ -154,8 +245,12 @@ impl EscrowContract {
     ///
     /// Returns the agreement or None if not found
     pub async fn get_agreement(&self, agreement_id: u128) -> Result<Option<Agreement>> {
-        // In production, this would query contract storage
-        Ok(None)
+        self.query(
+            "get_agreement",
+            &[Value::u128(agreement_id)],
+            self.contract_address.clone(),
+        )
+        .await
     }
 
     /// Get milestone details
@@ -173,8 +268,12 @@ impl EscrowContract {
         agreement_id: u128,
         milestone_index: u32,
     ) -> Result<Option<Milestone>> {
-        // In production, this would query contract storage
-        Ok(None)
+        self.query(
+            "get_milestone",
+            &[Value::u128(agreement_id), Value::u128(milestone_index as u128)],
+            self.contract_address.clone(),
+        )
+        .await
     }
 
     /// Get milestone count for an agreement
@@ -187,8 +286,13 @@ impl EscrowContract {
     ///
     /// Returns the number of milestones
     pub async fn get_milestone_count(&self, agreement_id: u128) -> Result<u32> {
-        // In production, this would query contract storage
-        Ok(0)
+        self.query_value(
+            "get_milestone_count",
+            &[Value::u128(agreement_id)],
+            self.contract_address.clone(),
+            0,
+        )
+        .await
     }
 
     /// Get all milestones for an agreement (convenience method)
@@ -213,21 +317,3 @@ impl EscrowContract {
         Ok(milestones)
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_contract_result() {
-        let ok_result: ContractResult<u128> = ContractResult::ok(42);
-        assert!(ok_result.success);
-        assert_eq!(ok_result.data, Some(42));
-        assert!(ok_result.error.is_none());
-
-        let err_result: ContractResult<u128> = ContractResult::err("Test error");
-        assert!(!err_result.success);
-        assert!(err_result.data.is_none());
-        assert_eq!(err_result.error, Some("Test error".to_string()));
-    }
-}
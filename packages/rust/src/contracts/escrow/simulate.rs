@@ -0,0 +1,250 @@
+//! Off-chain escrow state-machine simulator
+//!
+//! Steps an [`Agreement`] and its milestones through the same transitions the
+//! on-chain contract would apply, so a client can preview the outcome of a
+//! sequence of actions (and catch timeout/deadline issues) before signing
+//! anything. Inspired by Marlowe's "apply input, then apply timeouts"
+//! contract semantics.
+
+use super::super::types::*;
+
+/// An input applied to a [`SimState`] by [`step`]
+#[derive(Debug, Clone)]
+pub enum Action {
+    /// The client deposits `amount` into escrow
+    Deposit { from: AccountId, amount: Balance },
+    /// The provider marks milestone `index` as completed
+    CompleteMilestone { index: usize },
+    /// Either party raises a dispute over milestone `index`
+    RaiseDispute { index: usize },
+    /// The oracle (or timeout) resolves milestone `index` in favor of a party
+    Resolve { index: usize, choice: VoteChoice },
+}
+
+/// A non-fatal issue surfaced while applying an [`Action`] or [`project`]ing
+/// the state forward in time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Warning {
+    /// A `Deposit` of zero value
+    DepositTooSmall,
+    /// A milestone was marked completed after its `deadline` had already passed
+    MilestonePastDeadline,
+    /// A dispute was raised after `dispute_timeout` had elapsed
+    DisputeTimeoutElapsed,
+    /// A payout would exceed what's actually locked in escrow
+    PayoutExceedsDeposited,
+}
+
+/// Running per-party balances, tracked as the simulator applies actions
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+    /// Deposited funds the client could still be refunded
+    pub client_refundable: Balance,
+    /// Funds the provider has been awarded
+    pub provider_earned: Balance,
+    /// Funds still held by the escrow, not yet attributed to either party
+    pub locked_in_escrow: Balance,
+}
+
+/// The simulator's view of an in-flight agreement
+#[derive(Debug, Clone)]
+pub struct SimState {
+    pub agreement: Agreement,
+    pub milestones: Vec<Milestone>,
+    pub ledger: Ledger,
+}
+
+impl SimState {
+    /// Start simulating from a freshly created (undeposited) agreement
+    pub fn new(agreement: Agreement, milestones: Vec<Milestone>) -> Self {
+        Self {
+            agreement,
+            milestones,
+            ledger: Ledger::default(),
+        }
+    }
+}
+
+/// The balances and milestone statuses implied by a [`SimState`] at a given time
+#[derive(Debug, Clone)]
+pub struct Projection {
+    pub ledger: Ledger,
+    pub milestones: Vec<Milestone>,
+}
+
+/// Apply a single `action` to `state` as of `now`, returning the resulting
+/// state and any warnings raised along the way
+///
+/// Pure and side-effect-free: `state` is consumed and a new one returned, so
+/// callers can replay a plan of actions and inspect every intermediate step.
+pub fn step(mut state: SimState, action: Action, now: Timestamp) -> (SimState, Vec<Warning>) {
+    let mut warnings = Vec::new();
+
+    match action {
+        Action::Deposit { from: _, amount } => {
+            if amount == 0 {
+                warnings.push(Warning::DepositTooSmall);
+            }
+            state.agreement.deposited_amount += amount;
+            state.ledger.locked_in_escrow += amount;
+        }
+        Action::CompleteMilestone { index } => {
+            if let Some(milestone) = state.milestones.get_mut(index) {
+                if now > milestone.deadline {
+                    warnings.push(Warning::MilestonePastDeadline);
+                }
+                if milestone.status == MilestoneStatus::Pending {
+                    milestone.status = MilestoneStatus::Completed;
+                }
+            }
+        }
+        Action::RaiseDispute { index } => {
+            if now > state.agreement.dispute_timeout {
+                warnings.push(Warning::DisputeTimeoutElapsed);
+            }
+            if let Some(milestone) = state.milestones.get_mut(index) {
+                milestone.status = MilestoneStatus::Disputed;
+            }
+        }
+        Action::Resolve { index, choice } => {
+            if let Some(milestone) = state.milestones.get_mut(index) {
+                if milestone.amount > state.ledger.locked_in_escrow {
+                    warnings.push(Warning::PayoutExceedsDeposited);
+                }
+                let payout = milestone.amount.min(state.ledger.locked_in_escrow);
+                state.ledger.locked_in_escrow -= payout;
+                match choice {
+                    VoteChoice::InFavorOfClaimant => state.ledger.client_refundable += payout,
+                    VoteChoice::InFavorOfDefendant => state.ledger.provider_earned += payout,
+                }
+                milestone.status = MilestoneStatus::Resolved;
+            }
+        }
+    }
+
+    (state, warnings)
+}
+
+/// Project `state` forward to `now`, auto-applying deadline/timeout
+/// transitions that don't require an explicit [`Action`]
+///
+/// Mirrors how Marlowe applies a contract's timeout continuation when no
+/// input arrives in time: a `Pending` milestone whose `deadline` has passed
+/// without oracle verification moves to `Disputed`.
+pub fn project(state: &SimState, now: Timestamp) -> Projection {
+    let mut milestones = state.milestones.clone();
+
+    for milestone in milestones.iter_mut() {
+        if milestone.status == MilestoneStatus::Pending
+            && now > milestone.deadline
+            && !milestone.oracle_verification
+        {
+            milestone.status = MilestoneStatus::Disputed;
+        }
+    }
+
+    Projection {
+        ledger: state.ledger.clone(),
+        milestones,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::crypto::AccountId32;
+
+    fn agreement(client: AccountId, provider: AccountId) -> Agreement {
+        Agreement {
+            client,
+            provider,
+            total_amount: 1000,
+            deposited_amount: 0,
+            created_at: 1_700_000_000,
+            dispute_timeout: 1_700_100_000,
+            oracle: None,
+            is_active: true,
+        }
+    }
+
+    fn milestone(amount: Balance, deadline: Timestamp) -> Milestone {
+        Milestone {
+            description: "Design".to_string(),
+            amount,
+            status: MilestoneStatus::Pending,
+            deadline,
+            oracle_verification: false,
+            release_condition: ReleaseCondition::Paid,
+        }
+    }
+
+    #[test]
+    fn test_deposit_warns_on_zero_amount() {
+        let state = SimState::new(
+            agreement(AccountId32::new([1u8; 32]), AccountId32::new([2u8; 32])),
+            vec![milestone(1000, 1_700_050_000)],
+        );
+
+        let (state, warnings) = step(
+            state,
+            Action::Deposit { from: AccountId32::new([1u8; 32]), amount: 0 },
+            1_700_000_000,
+        );
+
+        assert_eq!(warnings, vec![Warning::DepositTooSmall]);
+        assert_eq!(state.ledger.locked_in_escrow, 0);
+    }
+
+    #[test]
+    fn test_resolve_splits_ledger_by_vote_choice() {
+        let state = SimState::new(
+            agreement(AccountId32::new([1u8; 32]), AccountId32::new([2u8; 32])),
+            vec![milestone(1000, 1_700_050_000)],
+        );
+
+        let (state, _) = step(
+            state,
+            Action::Deposit { from: AccountId32::new([1u8; 32]), amount: 1000 },
+            1_700_000_000,
+        );
+        let (state, warnings) = step(
+            state,
+            Action::Resolve { index: 0, choice: VoteChoice::InFavorOfDefendant },
+            1_700_010_000,
+        );
+
+        assert!(warnings.is_empty());
+        assert_eq!(state.ledger.provider_earned, 1000);
+        assert_eq!(state.ledger.locked_in_escrow, 0);
+        assert_eq!(state.milestones[0].status, MilestoneStatus::Resolved);
+    }
+
+    #[test]
+    fn test_resolve_warns_when_payout_exceeds_deposited() {
+        let state = SimState::new(
+            agreement(AccountId32::new([1u8; 32]), AccountId32::new([2u8; 32])),
+            vec![milestone(1000, 1_700_050_000)],
+        );
+
+        let (state, warnings) = step(
+            state,
+            Action::Resolve { index: 0, choice: VoteChoice::InFavorOfDefendant },
+            1_700_010_000,
+        );
+
+        assert_eq!(warnings, vec![Warning::PayoutExceedsDeposited]);
+        assert_eq!(state.ledger.provider_earned, 0);
+    }
+
+    #[test]
+    fn test_project_moves_overdue_unverified_milestone_to_disputed() {
+        let state = SimState::new(
+            agreement(AccountId32::new([1u8; 32]), AccountId32::new([2u8; 32])),
+            vec![milestone(1000, 1_700_050_000)],
+        );
+
+        let projection = project(&state, 1_700_060_000);
+
+        assert_eq!(projection.milestones[0].status, MilestoneStatus::Disputed);
+    }
+}
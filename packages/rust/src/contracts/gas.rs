@@ -0,0 +1,211 @@
+//! Gas/weight estimation via the contracts pallet's `ContractsApi_call`
+//! runtime API
+//!
+//! Dry-running a call before submitting it avoids both under-provisioning
+//! (transaction rejected at inclusion time) and over-provisioning (wasted
+//! `storage_deposit_limit` headroom).
+
+use super::types::*;
+use anyhow::{anyhow, Result};
+use parity_scale_codec::{Decode, Encode};
+use subxt::OnlineClient;
+
+/// A contract call to dry-run (or, once signed, submit)
+#[derive(Debug, Clone)]
+pub struct ContractCall {
+    pub caller: AccountId,
+    pub contract: AccountId,
+    pub value: Balance,
+    /// SCALE-encoded selector + arguments
+    pub input_data: Vec<u8>,
+}
+
+/// SCALE-compatible mirror of `sp_weights::Weight`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct Weight {
+    pub ref_time: u64,
+    pub proof_size: u64,
+}
+
+/// Result of dry-running a [`ContractCall`] via `ContractsApi_call`
+#[derive(Debug, Clone)]
+pub struct GasEstimate {
+    /// The simulated weight plus the chain's base extrinsic weight, safe to
+    /// submit as `gas_limit` without under-provisioning
+    pub gas_limit: GasLimit,
+    pub storage_deposit: Balance,
+    pub debug_message: String,
+    pub return_data: Vec<u8>,
+}
+
+#[derive(Debug, Decode)]
+struct RawExecReturnValue {
+    flags: u32,
+    data: Vec<u8>,
+}
+
+// Mirrors `pallet_contracts_primitives::ContractExecResult` closely enough to
+// pull the fields this SDK needs. The pallet's `result` field is really
+// `Result<ExecReturnValue, DispatchError>`, but decoding the full
+// `DispatchError` enum requires the chain's metadata; here we keep the `Err`
+// side as the raw encoded bytes and surface them as an opaque error message.
+#[derive(Debug, Decode)]
+struct RawContractExecResult {
+    gas_consumed: Weight,
+    gas_required: Weight,
+    storage_deposit: RawStorageDeposit,
+    debug_message: Vec<u8>,
+    result: Result<RawExecReturnValue, Vec<u8>>,
+}
+
+#[derive(Debug, Decode)]
+enum RawStorageDeposit {
+    Refund(Balance),
+    Charge(Balance),
+}
+
+#[derive(Encode)]
+struct ContractsApiCallArgs {
+    origin: AccountId,
+    dest: AccountId,
+    value: Balance,
+    gas_limit: Option<Weight>,
+    storage_deposit_limit: Option<Balance>,
+    input_data: Vec<u8>,
+}
+
+/// Dry-run `call` against the contracts pallet's `ContractsApi_call` runtime
+/// API without submitting anything, returning the simulated gas/storage cost
+/// and the call's SCALE-encoded return value
+///
+/// Generic over `C` so it works against any chain config, not just
+/// `PolkadotConfig` — the runtime API call is encoded/decoded by hand and
+/// doesn't touch `C`'s associated types.
+pub async fn estimate_gas<C: subxt::Config>(
+    client: &OnlineClient<C>,
+    call: ContractCall,
+) -> Result<GasEstimate> {
+    let args = ContractsApiCallArgs {
+        origin: call.caller,
+        dest: call.contract,
+        value: call.value,
+        gas_limit: None,
+        storage_deposit_limit: None,
+        input_data: call.input_data,
+    };
+
+    let runtime_api = client.runtime_api().at_latest().await?;
+    let raw: RawContractExecResult = runtime_api
+        .call_raw("ContractsApi_call", Some(args.encode().as_slice()))
+        .await?;
+
+    let return_data = match raw.result {
+        Ok(value) => value.data,
+        Err(encoded_error) => {
+            let hex_error: String = encoded_error.iter().map(|b| format!("{b:02x}")).collect();
+            return Err(anyhow!("dry run reverted (encoded DispatchError: 0x{hex_error})"));
+        }
+    };
+
+    let storage_deposit = match raw.storage_deposit {
+        RawStorageDeposit::Refund(amount) => amount,
+        RawStorageDeposit::Charge(amount) => amount,
+    };
+
+    let base_extrinsic = base_extrinsic_weight(client)?;
+
+    Ok(GasEstimate {
+        gas_limit: GasLimit {
+            ref_time: raw.gas_required.ref_time + base_extrinsic.ref_time,
+            proof_size: raw.gas_required.proof_size + base_extrinsic.proof_size,
+            storage_deposit_limit: Some(storage_deposit),
+        },
+        storage_deposit,
+        debug_message: String::from_utf8_lossy(&raw.debug_message).into_owned(),
+        return_data,
+    })
+}
+
+impl GasEstimate {
+    /// Scale this estimate's `gas_limit` up by `percent` extra headroom on
+    /// top of the dry run (e.g. `20` for 20%), for chains or contracts where
+    /// the dry run's weight runs a little optimistic relative to the real
+    /// extrinsic and a bare estimate risks `OutOfGas`/`StorageDepositLimitExhausted`
+    pub fn with_safety_margin(&self, percent: u8) -> GasLimit {
+        let scale_u64 = |v: u64| v.saturating_mul(100 + percent as u64) / 100;
+        let scale_u128 = |v: u128| v.saturating_mul(100 + percent as u128) / 100;
+
+        GasLimit {
+            ref_time: scale_u64(self.gas_limit.ref_time),
+            proof_size: scale_u64(self.gas_limit.proof_size),
+            storage_deposit_limit: self.gas_limit.storage_deposit_limit.map(scale_u128),
+        }
+    }
+}
+
+/// The chain's per-extrinsic weight floor (`BlockWeights::get().get(Normal).base_extrinsic`),
+/// added on top of a dry run's simulated weight so the recommended `gas_limit`
+/// doesn't under-provision and get the extrinsic rejected at inclusion time
+async fn base_extrinsic_weight<C: subxt::Config>(client: &OnlineClient<C>) -> Result<Weight> {
+    let address = subxt::dynamic::constant("System", "BlockWeights");
+    let value = client.constants().at(&address)?;
+
+    let base = value
+        .at("per_class")
+        .and_then(|v| v.at("normal"))
+        .and_then(|v| v.at("base_extrinsic"))
+        .ok_or_else(|| anyhow!("unexpected shape for System::BlockWeights constant"))?;
+
+    Ok(Weight {
+        ref_time: base
+            .at("ref_time")
+            .and_then(|v| v.as_u128())
+            .ok_or_else(|| anyhow!("missing ref_time in base_extrinsic weight"))? as u64,
+        proof_size: base
+            .at("proof_size")
+            .and_then(|v| v.as_u128())
+            .unwrap_or(0) as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_safety_margin_scales_weight_and_deposit() {
+        let estimate = GasEstimate {
+            gas_limit: GasLimit {
+                ref_time: 1_000_000,
+                proof_size: 10_000,
+                storage_deposit_limit: Some(500),
+            },
+            storage_deposit: 500,
+            debug_message: String::new(),
+            return_data: Vec::new(),
+        };
+
+        let padded = estimate.with_safety_margin(20);
+
+        assert_eq!(padded.ref_time, 1_200_000);
+        assert_eq!(padded.proof_size, 12_000);
+        assert_eq!(padded.storage_deposit_limit, Some(600));
+    }
+
+    #[test]
+    fn test_with_safety_margin_zero_percent_is_a_no_op() {
+        let estimate = GasEstimate {
+            gas_limit: GasLimit {
+                ref_time: 1_000_000,
+                proof_size: 10_000,
+                storage_deposit_limit: None,
+            },
+            storage_deposit: 0,
+            debug_message: String::new(),
+            return_data: Vec::new(),
+        };
+
+        let padded = estimate.with_safety_margin(0);
+        assert_eq!(padded, estimate.gas_limit);
+    }
+}
@@ -0,0 +1,287 @@
+//! ink! contract-metadata loader
+//!
+//! Parses the metadata bundle `cargo contract build` produces (the
+//! `.contract`/metadata JSON file) and uses its message selectors and
+//! scale-info type registry to build `Contracts::call` input data and
+//! decode return values, instead of hand-encoding every message.
+
+use anyhow::{anyhow, Context, Result};
+use parity_scale_codec::Encode;
+use scale_info::PortableRegistry;
+use scale_value::Value;
+use serde_json::Value as Json;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+struct MessageSpec {
+    selector: [u8; 4],
+    arg_type_ids: Vec<u32>,
+}
+
+/// One `#[ink(event)]` variant: its field labels/types, in declaration order
+///
+/// ink!'s derived `scale::Encode for Event` encodes the emitted event as a
+/// plain Rust enum: a leading `u8` discriminant (this event's position in
+/// `spec.events`) followed by its fields, in the same way `encode_call`
+/// prefixes a message's 4-byte selector onto its arguments.
+#[derive(Clone)]
+struct EventSpec {
+    label: String,
+    field_type_ids: Vec<(String, u32)>,
+}
+
+/// A decoded `#[ink(event)]`, keyed by field label
+#[derive(Debug, Clone)]
+pub struct DecodedEvent {
+    pub label: String,
+    pub fields: Vec<(String, Value<u32>)>,
+}
+
+impl DecodedEvent {
+    /// Look up a field by label
+    pub fn field(&self, name: &str) -> Option<&Value<u32>> {
+        self.fields.iter().find(|(label, _)| label == name).map(|(_, value)| value)
+    }
+}
+
+/// A loaded ink! contract metadata bundle, indexed by message label
+#[derive(Clone)]
+pub struct ContractMetadata {
+    registry: PortableRegistry,
+    messages: HashMap<String, MessageSpec>,
+    events: Vec<EventSpec>,
+}
+
+impl ContractMetadata {
+    /// Parse a `.contract`/metadata JSON bundle
+    pub fn from_json(bundle: &str) -> Result<Self> {
+        let json: Json = serde_json::from_str(bundle).context("invalid metadata JSON")?;
+
+        let types = json
+            .get("types")
+            .ok_or_else(|| anyhow!("metadata missing 'types' registry"))?;
+        let registry: PortableRegistry =
+            serde_json::from_value(serde_json::json!({ "types": types }))
+                .context("invalid 'types' registry")?;
+
+        let messages_json = json
+            .pointer("/spec/messages")
+            .and_then(Json::as_array)
+            .ok_or_else(|| anyhow!("metadata missing spec.messages"))?;
+
+        let mut messages = HashMap::new();
+        for message in messages_json {
+            let label = message
+                .get("label")
+                .and_then(Json::as_str)
+                .ok_or_else(|| anyhow!("message missing label"))?
+                .to_string();
+
+            let selector_hex = message
+                .get("selector")
+                .and_then(Json::as_str)
+                .ok_or_else(|| anyhow!("message '{label}' missing selector"))?;
+            let selector = parse_selector(selector_hex)?;
+
+            let arg_type_ids = message
+                .get("args")
+                .and_then(Json::as_array)
+                .map(|args| {
+                    args.iter()
+                        .map(|arg| arg.pointer("/type/type").and_then(Json::as_u64))
+                        .collect::<Option<Vec<_>>>()
+                        .map(|ids| ids.into_iter().map(|id| id as u32).collect::<Vec<_>>())
+                })
+                .flatten()
+                .ok_or_else(|| anyhow!("message '{label}' has malformed args"))?;
+
+            messages.insert(label, MessageSpec { selector, arg_type_ids });
+        }
+
+        let events_json = json
+            .pointer("/spec/events")
+            .and_then(Json::as_array)
+            .cloned()
+            .unwrap_or_default();
+
+        let mut events = Vec::with_capacity(events_json.len());
+        for event in &events_json {
+            let label = event
+                .get("label")
+                .and_then(Json::as_str)
+                .ok_or_else(|| anyhow!("event missing label"))?
+                .to_string();
+
+            let field_type_ids = event
+                .get("args")
+                .and_then(Json::as_array)
+                .map(|args| {
+                    args.iter()
+                        .map(|arg| {
+                            let label = arg
+                                .get("label")
+                                .and_then(Json::as_str)
+                                .unwrap_or_default()
+                                .to_string();
+                            let type_id = arg.pointer("/type/type").and_then(Json::as_u64)? as u32;
+                            Some((label, type_id))
+                        })
+                        .collect::<Option<Vec<_>>>()
+                })
+                .flatten()
+                .ok_or_else(|| anyhow!("event '{label}' has malformed args"))?;
+
+            events.push(EventSpec { label, field_type_ids });
+        }
+
+        Ok(Self { registry, messages, events })
+    }
+
+    fn message(&self, msg: &str) -> Result<&MessageSpec> {
+        self.messages
+            .get(msg)
+            .ok_or_else(|| anyhow!("unknown message '{msg}' in contract metadata"))
+    }
+
+    /// The 4-byte selector ink! uses to dispatch calls to `msg`
+    pub fn selector(&self, msg: &str) -> Result<[u8; 4]> {
+        Ok(self.message(msg)?.selector)
+    }
+
+    /// Build the `Contracts::call` input data for `msg`: its selector
+    /// followed by its SCALE-encoded arguments, in declaration order
+    pub fn encode_call(&self, msg: &str, args: &[Value<u32>]) -> Result<Vec<u8>> {
+        let spec = self.message(msg)?;
+        if args.len() != spec.arg_type_ids.len() {
+            return Err(anyhow!(
+                "message '{msg}' expects {} argument(s), got {}",
+                spec.arg_type_ids.len(),
+                args.len()
+            ));
+        }
+
+        let mut encoded = spec.selector.to_vec();
+        for (arg, type_id) in args.iter().zip(&spec.arg_type_ids) {
+            let arg_bytes = scale_value::scale::encode_as_type(arg.clone(), *type_id, &self.registry)
+                .map_err(|e| anyhow!("failed to encode argument for '{msg}': {e}"))?;
+            encoded.extend(arg_bytes);
+        }
+
+        Ok(encoded)
+    }
+
+    /// Decode a `Contracts::ContractEmitted` payload against this contract's
+    /// `#[ink(event)]` definitions: the leading byte selects which event
+    /// variant was emitted (its position in `spec.events`), and the rest is
+    /// SCALE-decoded field by field using the registry
+    pub fn decode_event(&self, data: &[u8]) -> Result<DecodedEvent> {
+        let [variant_index, rest @ ..] = data else {
+            return Err(anyhow!("empty event payload"));
+        };
+        let spec = self
+            .events
+            .get(*variant_index as usize)
+            .ok_or_else(|| anyhow!("event variant index {variant_index} not in contract metadata"))?;
+
+        let mut input = rest;
+        let mut fields = Vec::with_capacity(spec.field_type_ids.len());
+        for (label, type_id) in &spec.field_type_ids {
+            let value = scale_value::scale::decode_as_type(&mut input, *type_id, &self.registry)
+                .map_err(|e| anyhow!("failed to decode field '{label}' of event '{}': {e}", spec.label))?;
+            fields.push((label.clone(), value));
+        }
+
+        Ok(DecodedEvent { label: spec.label.clone(), fields })
+    }
+}
+
+fn parse_selector(hex: &str) -> Result<[u8; 4]> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() != 8 {
+        return Err(anyhow!("selector '0x{hex}' is not 4 bytes"));
+    }
+
+    let mut selector = [0u8; 4];
+    for (i, byte) in selector.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+            .map_err(|_| anyhow!("selector '0x{hex}' is not valid hex"))?;
+    }
+    Ok(selector)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_METADATA: &str = r#"{
+        "types": [
+            { "id": 0, "type": { "path": [], "params": [], "def": { "primitive": "u128" } } }
+        ],
+        "spec": {
+            "messages": [
+                {
+                    "label": "get_dispute",
+                    "selector": "0xaabbccdd",
+                    "args": [],
+                    "returnType": { "type": 0 }
+                },
+                {
+                    "label": "vote",
+                    "selector": "0x11223344",
+                    "args": [ { "type": { "type": 0 } } ],
+                    "returnType": null
+                }
+            ],
+            "events": [
+                {
+                    "label": "DisputeCreated",
+                    "args": [ { "label": "dispute_id", "type": { "type": 0 } } ]
+                }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn test_selector_parses_hex() {
+        let metadata = ContractMetadata::from_json(SAMPLE_METADATA).unwrap();
+        assert_eq!(metadata.selector("get_dispute").unwrap(), [0xaa, 0xbb, 0xcc, 0xdd]);
+    }
+
+    #[test]
+    fn test_encode_call_prefixes_selector_before_args() {
+        let metadata = ContractMetadata::from_json(SAMPLE_METADATA).unwrap();
+        let encoded = metadata.encode_call("vote", &[Value::u128(1)]).unwrap();
+
+        assert_eq!(&encoded[..4], &[0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(&encoded[4..], 1u128.encode());
+    }
+
+    #[test]
+    fn test_unknown_message_errors() {
+        let metadata = ContractMetadata::from_json(SAMPLE_METADATA).unwrap();
+        assert!(metadata.selector("nonexistent").is_err());
+    }
+
+    #[test]
+    fn test_encode_call_rejects_wrong_arg_count() {
+        let metadata = ContractMetadata::from_json(SAMPLE_METADATA).unwrap();
+        assert!(metadata.encode_call("vote", &[]).is_err());
+    }
+
+    #[test]
+    fn test_decode_event_by_variant_index() {
+        let metadata = ContractMetadata::from_json(SAMPLE_METADATA).unwrap();
+        let mut data = vec![0u8]; // DisputeCreated is events[0]
+        data.extend(7u128.encode());
+
+        let event = metadata.decode_event(&data).unwrap();
+        assert_eq!(event.label, "DisputeCreated");
+        assert_eq!(event.field("dispute_id").and_then(|v| v.as_u128()), Some(7));
+    }
+
+    #[test]
+    fn test_decode_event_rejects_unknown_variant() {
+        let metadata = ContractMetadata::from_json(SAMPLE_METADATA).unwrap();
+        assert!(metadata.decode_event(&[99]).is_err());
+    }
+}
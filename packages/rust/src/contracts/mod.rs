@@ -10,11 +10,11 @@
 //!
 //! ```no_run
 //! use glin_sdk::contracts::{EscrowContract, CreateAgreementParams};
-//! use subxt::OnlineClient;
+//! use subxt::{OnlineClient, PolkadotConfig};
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! // Connect to GLIN network
-//! let client = OnlineClient::new().await?;
+//! let client = OnlineClient::<PolkadotConfig>::new().await?;
 //!
 //! // Initialize escrow contract
 //! let escrow = EscrowContract::new(client, "5Escrow...".parse()?);
@@ -28,6 +28,8 @@
 //!     dispute_timeout: 1234567890,
 //!     oracle: None,
 //!     value: 2_000_000_000_000_000_000_000,
+//!     token_address: None,
+//!     milestone_conditions: vec![None, None],
 //! };
 //!
 //! // let result = escrow.create_agreement(params, &keypair).await?;
@@ -52,16 +54,24 @@
 //! - Parameter types: `CreateAgreementParams`, `RegisterProfessionalParams`, etc.
 //! - Result type: `ContractResult<T>`
 
+pub mod codegen;
 pub mod types;
 pub mod escrow;
 pub mod registry;
 pub mod arbitration;
+pub mod token;
+pub mod gas;
+pub mod metadata;
 
 // Re-export main types
 pub use types::*;
 pub use escrow::EscrowContract;
+pub use escrow::simulate;
 pub use registry::RegistryContract;
 pub use arbitration::ArbitrationContract;
+pub use token::Psp22Contract;
+pub use gas::{ContractCall, GasEstimate};
+pub use metadata::{ContractMetadata, DecodedEvent};
 
 use anyhow::Result;
 use subxt::{OnlineClient, PolkadotConfig};
@@ -71,6 +81,13 @@ use subxt::{OnlineClient, PolkadotConfig};
 /// This is a convenience wrapper that provides access to all three
 /// contract clients: escrow, registry, and arbitration.
 ///
+/// Generic over `C: subxt::Config`, defaulting to [`PolkadotConfig`], so the
+/// same wrapper works against a chain with a custom GLIN runtime config (a
+/// different `AccountId`, `Hash`, or signature type) without forking this
+/// module — [`from_client`](Self::from_client) accepts any pre-built
+/// `OnlineClient<C>`, including one wired up over a mocked or offline
+/// `RpcClient` for tests.
+///
 /// # Example
 ///
 /// ```no_run
@@ -82,6 +99,7 @@ use subxt::{OnlineClient, PolkadotConfig};
 ///     Some("5Escrow...".parse()?),
 ///     Some("5Registry...".parse()?),
 ///     Some("5Arbitration...".parse()?),
+///     None,
 /// ).await?;
 ///
 /// // Access individual contracts
@@ -91,14 +109,15 @@ use subxt::{OnlineClient, PolkadotConfig};
 /// # Ok(())
 /// # }
 /// ```
-pub struct GlinContracts {
-    client: OnlineClient<PolkadotConfig>,
-    pub escrow: EscrowContract,
-    pub registry: RegistryContract,
-    pub arbitration: ArbitrationContract,
+pub struct GlinContracts<C: subxt::Config = PolkadotConfig> {
+    client: OnlineClient<C>,
+    pub escrow: EscrowContract<C>,
+    pub registry: RegistryContract<C>,
+    pub arbitration: ArbitrationContract<C>,
+    pub token: Psp22Contract<C>,
 }
 
-impl GlinContracts {
+impl<C: subxt::Config> GlinContracts<C> {
     /// Create a new contracts client
     ///
     /// # Arguments
@@ -107,35 +126,29 @@ impl GlinContracts {
     /// * `escrow_address` - Optional GenericEscrow contract address
     /// * `registry_address` - Optional ProfessionalRegistry contract address
     /// * `arbitration_address` - Optional ArbitrationDAO contract address
+    /// * `token_address` - Optional PSP22 token contract address, for agreements
+    ///   that settle milestones in a token instead of the native balance
     pub async fn new(
         rpc_url: &str,
         escrow_address: Option<AccountId>,
         registry_address: Option<AccountId>,
         arbitration_address: Option<AccountId>,
+        token_address: Option<AccountId>,
     ) -> Result<Self> {
-        let client = OnlineClient::<PolkadotConfig>::from_url(rpc_url).await?;
-
-        // Use zero address as placeholder if not provided
-        let zero_address = AccountId::from([0u8; 32]);
+        let client = OnlineClient::<C>::from_url(rpc_url).await?;
 
-        Ok(Self {
-            escrow: EscrowContract::new(
-                client.clone(),
-                escrow_address.unwrap_or(zero_address.clone()),
-            ),
-            registry: RegistryContract::new(
-                client.clone(),
-                registry_address.unwrap_or(zero_address.clone()),
-            ),
-            arbitration: ArbitrationContract::new(
-                client.clone(),
-                arbitration_address.unwrap_or(zero_address),
-            ),
+        Ok(Self::from_client(
             client,
-        })
+            escrow_address,
+            registry_address,
+            arbitration_address,
+            token_address,
+        ))
     }
 
-    /// Create from existing client
+    /// Create from an existing, already-connected `OnlineClient<C>` — e.g.
+    /// one built over a mocked or offline `RpcClient` for tests, instead of
+    /// always dialing a live RPC URL
     ///
     /// # Arguments
     ///
@@ -143,11 +156,13 @@ impl GlinContracts {
     /// * `escrow_address` - Optional GenericEscrow contract address
     /// * `registry_address` - Optional ProfessionalRegistry contract address
     /// * `arbitration_address` - Optional ArbitrationDAO contract address
+    /// * `token_address` - Optional PSP22 token contract address
     pub fn from_client(
-        client: OnlineClient<PolkadotConfig>,
+        client: OnlineClient<C>,
         escrow_address: Option<AccountId>,
         registry_address: Option<AccountId>,
         arbitration_address: Option<AccountId>,
+        token_address: Option<AccountId>,
     ) -> Self {
         let zero_address = AccountId::from([0u8; 32]);
 
@@ -162,14 +177,36 @@ impl GlinContracts {
             ),
             arbitration: ArbitrationContract::new(
                 client.clone(),
-                arbitration_address.unwrap_or(zero_address),
+                arbitration_address.unwrap_or(zero_address.clone()),
+            ),
+            token: Psp22Contract::new(
+                client.clone(),
+                token_address.unwrap_or(zero_address),
             ),
             client,
         }
     }
 
     /// Get reference to the underlying client
-    pub fn client(&self) -> &OnlineClient<PolkadotConfig> {
+    pub fn client(&self) -> &OnlineClient<C> {
         &self.client
     }
+
+    /// Apply a fixed gas limit to every transaction across all four contract
+    /// clients, skipping per-call estimation
+    pub fn set_fixed_gas(&mut self, gas_limit: GasLimit) {
+        let gas_mode = GasMode::Fixed(gas_limit);
+        self.escrow.set_gas_mode(gas_mode);
+        self.registry.set_gas_mode(gas_mode);
+        self.arbitration.set_gas_mode(gas_mode);
+        self.token.set_gas_mode(gas_mode);
+    }
+
+    /// Revert all four contract clients to per-call gas estimation
+    pub fn set_gas_estimation(&mut self) {
+        self.escrow.set_gas_mode(GasMode::Estimate);
+        self.registry.set_gas_mode(GasMode::Estimate);
+        self.arbitration.set_gas_mode(GasMode::Estimate);
+        self.token.set_gas_mode(GasMode::Estimate);
+    }
 }
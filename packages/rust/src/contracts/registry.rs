@@ -1,8 +1,11 @@
 //! ProfessionalRegistry contract client
 
+use super::gas::{self, ContractCall, GasEstimate};
+use super::metadata::ContractMetadata;
 use super::types::*;
 use anyhow::Result;
-use sp_core::sr25519::Pair;
+use crate::signer::Signer;
+use scale_value::Value;
 use subxt::{OnlineClient, PolkadotConfig};
 
 /// Client for interacting with ProfessionalRegistry smart contract
@@ -16,7 +19,7 @@ use subxt::{OnlineClient, PolkadotConfig};
 /// use glin_sdk::contracts::{RegistryContract, RegisterProfessionalParams, ProfessionalRole};
 ///
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-/// let client = subxt::OnlineClient::new().await?;
+/// let client = subxt::OnlineClient::<subxt::PolkadotConfig>::new().await?;
 /// let registry = RegistryContract::new(client, "5Registry...".parse()?);
 ///
 /// let params = RegisterProfessionalParams {
@@ -29,42 +32,34 @@ use subxt::{OnlineClient, PolkadotConfig};
 /// # Ok(())
 /// # }
 /// ```
-pub struct RegistryContract {
-    client: OnlineClient<PolkadotConfig>,
-    contract_address: AccountId,
-}
+crate::contract_client_scaffold!(RegistryContract);
 
-impl RegistryContract {
-    /// Create a new registry contract client
-    pub fn new(client: OnlineClient<PolkadotConfig>, contract_address: AccountId) -> Self {
-        Self {
-            client,
-            contract_address,
-        }
-    }
-
-    /// Update contract address
-    pub fn set_contract_address(&mut self, address: AccountId) {
-        self.contract_address = address;
-    }
+/// Accounts scanned per [`RegistryContract::list_professionals`] page, so a
+/// large registry can't be pulled into memory (or dry-run) in one call
+const LIST_PROFESSIONALS_PAGE_SIZE: u32 = 50;
 
+impl<C: subxt::Config> RegistryContract<C>
+where
+    crate::signer::Signer: subxt::tx::Signer<C>,
+{
     /// Register as a professional
     ///
     /// # Arguments
     ///
     /// * `params` - Registration parameters
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     pub async fn register(
         &self,
         params: RegisterProfessionalParams,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<()>> {
-        // In production, this would:
-        // 1. Encode the contract call using metadata
-        // 2. Create a Contracts::call extrinsic with value = stake_amount
-        // 3. Sign and submit the transaction
-
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let args = [role_to_value(params.role), Value::string(params.metadata_uri)];
+        let result = self
+            .submit_call("register", &args, params.stake_amount, signer, gas_limit)
+            .await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Increase stake amount
@@ -73,12 +68,17 @@ impl RegistryContract {
     ///
     /// * `additional_stake` - Additional stake amount
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     pub async fn increase_stake(
         &self,
         additional_stake: Balance,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<()>> {
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let result = self
+            .submit_call("increase_stake", &[], additional_stake, signer, gas_limit)
+            .await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Submit a review for a professional
@@ -87,16 +87,24 @@ impl RegistryContract {
     ///
     /// * `params` - Review parameters
     /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
     pub async fn submit_review(
         &self,
         params: SubmitReviewParams,
-        signer: &Pair,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
     ) -> Result<ContractResult<()>> {
         if params.rating < 1 || params.rating > 5 {
             return Ok(ContractResult::err("Rating must be between 1 and 5"));
         }
 
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+        let args = [
+            Value::from_bytes(params.professional.as_ref()),
+            Value::u128(params.rating as u128),
+            Value::string(params.comment),
+        ];
+        let result = self.submit_call("submit_review", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Withdraw stake (deactivates profile)
@@ -104,8 +112,14 @@ impl RegistryContract {
     /// # Arguments
     ///
     /// * `signer` - Keypair for signing the transaction
-    pub async fn withdraw_stake(&self, signer: &Pair) -> Result<ContractResult<()>> {
-        Ok(ContractResult::err("Not implemented - requires contract metadata"))
+    /// * `gas_limit` - Optional explicit gas override for this call
+    pub async fn withdraw_stake(
+        &self,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
+    ) -> Result<ContractResult<()>> {
+        let result = self.submit_call("withdraw_stake", &[], 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
     }
 
     /// Get professional profile
@@ -118,8 +132,12 @@ impl RegistryContract {
     ///
     /// Returns the profile or None if not found
     pub async fn get_profile(&self, account: &AccountId) -> Result<Option<ProfessionalProfile>> {
-        // In production, this would query contract storage
-        Ok(None)
+        self.query(
+            "get_profile",
+            &[Value::from_bytes(account.as_ref())],
+            self.contract_address.clone(),
+        )
+        .await
     }
 
     /// Get review by index
@@ -137,8 +155,12 @@ impl RegistryContract {
         professional: &AccountId,
         review_index: u32,
     ) -> Result<Option<Review>> {
-        // In production, this would query contract storage
-        Ok(None)
+        self.query(
+            "get_review",
+            &[Value::from_bytes(professional.as_ref()), Value::u128(review_index as u128)],
+            self.contract_address.clone(),
+        )
+        .await
     }
 
     /// Get review count for a professional
@@ -151,8 +173,13 @@ impl RegistryContract {
     ///
     /// Returns the number of reviews
     pub async fn get_review_count(&self, professional: &AccountId) -> Result<u32> {
-        // In production, this would query contract storage
-        Ok(0)
+        self.query_value(
+            "get_review_count",
+            &[Value::from_bytes(professional.as_ref())],
+            self.contract_address.clone(),
+            0,
+        )
+        .await
     }
 
     /// Get minimum stake required for a role
@@ -165,8 +192,13 @@ impl RegistryContract {
     ///
     /// Returns the minimum stake amount
     pub async fn get_min_stake(&self, role: ProfessionalRole) -> Result<Balance> {
-        // In production, this would query contract storage
-        Ok(0)
+        self.query_value(
+            "get_min_stake",
+            &[role_to_value(role)],
+            self.contract_address.clone(),
+            0,
+        )
+        .await
     }
 
     /// Check if account is an active professional
@@ -179,8 +211,7 @@ impl RegistryContract {
     ///
     /// Returns true if active professional, false otherwise
     pub async fn is_active_professional(&self, account: &AccountId) -> Result<bool> {
-        // In production, this would query contract storage
-        Ok(false)
+        Ok(self.get_profile(account).await?.is_some_and(|profile| profile.is_active))
     }
 
     /// Get all reviews for a professional (convenience method)
@@ -224,6 +255,254 @@ impl RegistryContract {
         let sum: u32 = reviews.iter().map(|r| r.rating as u32).sum();
         Ok(sum as f32 / reviews.len() as f32)
     }
+
+    /// Browse registered professionals matching `filter`, paginated through
+    /// the registry's account index
+    ///
+    /// Fetches one bounded page of accounts from the contract's `list_accounts`
+    /// index message (offset/limit, newest-registered first), then filters
+    /// them client-side against `filter` — dry-running `get_profile` (and,
+    /// when `min_average_rating` is set, `get_all_reviews`) for each account
+    /// in the page.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Criteria to narrow the results by
+    /// * `cursor` - Pass `None` for the first page, then the previous page's
+    ///   `next_cursor` to continue
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of matching profiles plus a cursor for the next page
+    pub async fn list_professionals(
+        &self,
+        filter: ProfessionalFilter,
+        cursor: Option<u32>,
+    ) -> Result<ProfessionalPage> {
+        let offset = cursor.unwrap_or(0);
+        let accounts: Vec<AccountId> = self
+            .query_value(
+                "list_accounts",
+                &[Value::u128(offset as u128), Value::u128(LIST_PROFESSIONALS_PAGE_SIZE as u128)],
+                self.contract_address.clone(),
+                Vec::new(),
+            )
+            .await?;
+
+        let mut profiles = Vec::new();
+        for account in &accounts {
+            let Some(profile) = self.get_profile(account).await? else {
+                continue;
+            };
+            if !filter.matches_profile(&profile) {
+                continue;
+            }
+            if let Some(min_rating) = filter.min_average_rating {
+                if self.get_average_rating(account).await? < min_rating {
+                    continue;
+                }
+            }
+            profiles.push(profile);
+        }
+
+        let next_cursor =
+            (accounts.len() as u32 == LIST_PROFESSIONALS_PAGE_SIZE).then_some(offset + LIST_PROFESSIONALS_PAGE_SIZE);
+
+        Ok(ProfessionalPage { profiles, next_cursor })
+    }
+
+    /// Report misconduct against a professional
+    ///
+    /// Borrows the validator-set "report misconduct via contract call"
+    /// pattern: evidence is a metadata/IPFS URI rather than an on-chain blob,
+    /// and the contract itself decides (based on accumulated valid reports)
+    /// whether to slash the professional's stake, which shows up afterwards
+    /// in [`get_slash_history`](Self::get_slash_history) and
+    /// [`ProfessionalProfile::effective_stake`].
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Report parameters
+    /// * `signer` - Keypair for signing the transaction
+    /// * `gas_limit` - Optional explicit gas override for this call
+    ///
+    /// # Returns
+    ///
+    /// Returns the new report's index within `get_reports(params.professional)`
+    pub async fn report_misconduct(
+        &self,
+        params: ReportParams,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
+    ) -> Result<ContractResult<u128>> {
+        let args = [
+            Value::from_bytes(params.professional.as_ref()),
+            Value::string(params.evidence_uri),
+            Value::u128(params.reason_code as u128),
+        ];
+        let result = self.submit_call("report_misconduct", &args, 0, signer, gas_limit).await?;
+        if !result.success {
+            return Ok(ContractResult::err(result.error.unwrap_or_default()));
+        }
+
+        // Assumes the contract emits a `MisconductReported` event carrying the
+        // new report's index in a `report_index` field.
+        let Some(event) = result.data.as_ref().and_then(|events| events.first()) else {
+            return Ok(ContractResult::err("no ContractEmitted event for report_misconduct"));
+        };
+        let Some(report_index) = event.field("report_index").and_then(|v| v.as_u128()) else {
+            return Ok(ContractResult::err(format!(
+                "'{}' event missing u128 'report_index' field",
+                event.label
+            )));
+        };
+
+        Ok(ContractResult::ok(report_index))
+    }
+
+    /// Get all misconduct reports filed against a professional
+    ///
+    /// # Arguments
+    ///
+    /// * `professional` - Professional account address
+    pub async fn get_reports(&self, professional: &AccountId) -> Result<Vec<MisconductReport>> {
+        self.query_value(
+            "get_reports",
+            &[Value::from_bytes(professional.as_ref())],
+            self.contract_address.clone(),
+            Vec::new(),
+        )
+        .await
+    }
+
+    /// Get the history of stake slashes applied to a professional
+    ///
+    /// # Arguments
+    ///
+    /// * `professional` - Professional account address
+    pub async fn get_slash_history(&self, professional: &AccountId) -> Result<Vec<SlashRecord>> {
+        self.query_value(
+            "get_slash_history",
+            &[Value::from_bytes(professional.as_ref())],
+            self.contract_address.clone(),
+            Vec::new(),
+        )
+        .await
+    }
+
+    /// Stream this registry's events decoded into typed [`RegistryEvent`]
+    /// variants, instead of the generic `DecodedEvent` from
+    /// [`subscribe_events`](Self::subscribe_events)
+    ///
+    /// Events whose label or fields this client doesn't recognize are
+    /// silently skipped rather than surfaced as errors, since newer contract
+    /// versions may emit events this client predates.
+    pub fn subscribe_registry_events(
+        &self,
+    ) -> Result<impl futures_util::Stream<Item = Result<RegistryEvent>>> {
+        Ok(futures_util::StreamExt::filter_map(self.subscribe_events()?, |event| async move {
+            match event {
+                Ok(decoded) => RegistryEvent::from_decoded(&decoded).map(Ok),
+                Err(error) => Some(Err(error)),
+            }
+        }))
+    }
+
+    /// Like [`subscribe_registry_events`](Self::subscribe_registry_events),
+    /// filtered down to `ReviewSubmitted` events for a specific professional
+    pub fn subscribe_reviews(
+        &self,
+        professional: AccountId,
+    ) -> Result<impl futures_util::Stream<Item = Result<RegistryEvent>>> {
+        Ok(futures_util::StreamExt::filter_map(self.subscribe_registry_events()?, move |event| {
+            let matches = matches!(
+                &event,
+                Ok(RegistryEvent::ReviewSubmitted { professional: reviewed, .. }) if *reviewed == professional
+            );
+            async move { (matches || event.is_err()).then_some(event) }
+        }))
+    }
+}
+
+/// Typed ProfessionalRegistry contract events, decoded from the contract's
+/// raw `#[ink(event)]` variants via [`ContractMetadata::decode_event`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryEvent {
+    ProfessionalRegistered { account: AccountId, role: ProfessionalRole },
+    ReviewSubmitted { professional: AccountId, reviewer: AccountId, rating: u8 },
+    StakeIncreased { account: AccountId, additional_stake: Balance },
+    StakeWithdrawn { account: AccountId },
+}
+
+impl RegistryEvent {
+    /// Decode a generic [`DecodedEvent`](super::metadata::DecodedEvent) into
+    /// a typed registry event, returning `None` for event labels or fields
+    /// this client doesn't recognize
+    fn from_decoded(event: &super::metadata::DecodedEvent) -> Option<Self> {
+        Some(match event.label.as_str() {
+            "ProfessionalRegistered" => RegistryEvent::ProfessionalRegistered {
+                account: account_id_from_value(event.field("account")?)?,
+                role: role_from_value(event.field("role")?)?,
+            },
+            "ReviewSubmitted" => RegistryEvent::ReviewSubmitted {
+                professional: account_id_from_value(event.field("professional")?)?,
+                reviewer: account_id_from_value(event.field("reviewer")?)?,
+                rating: event.field("rating")?.as_u128()? as u8,
+            },
+            "StakeIncreased" => RegistryEvent::StakeIncreased {
+                account: account_id_from_value(event.field("account")?)?,
+                additional_stake: event.field("additional_stake")?.as_u128()?,
+            },
+            "StakeWithdrawn" => RegistryEvent::StakeWithdrawn {
+                account: account_id_from_value(event.field("account")?)?,
+            },
+            _ => return None,
+        })
+    }
+}
+
+/// Decode a 32-byte `AccountId` back out of the unnamed byte composite
+/// `Value::from_bytes` produces
+fn account_id_from_value(value: &Value<u32>) -> Option<AccountId> {
+    let scale_value::ValueDef::Composite(composite) = &value.value else {
+        return None;
+    };
+    let bytes: Vec<u8> = composite
+        .values()
+        .map(|byte| byte.as_u128().map(|b| b as u8))
+        .collect::<Option<_>>()?;
+
+    <[u8; 32]>::try_from(bytes).ok().map(AccountId::from)
+}
+
+/// The inverse of [`role_to_value`]
+fn role_from_value(value: &Value<u32>) -> Option<ProfessionalRole> {
+    let scale_value::ValueDef::Variant(variant) = &value.value else {
+        return None;
+    };
+    Some(match variant.name.as_str() {
+        "Lawyer" => ProfessionalRole::Lawyer,
+        "Doctor" => ProfessionalRole::Doctor,
+        "Arbitrator" => ProfessionalRole::Arbitrator,
+        "Notary" => ProfessionalRole::Notary,
+        "Auditor" => ProfessionalRole::Auditor,
+        "ConsultantOther" => ProfessionalRole::ConsultantOther,
+        _ => return None,
+    })
+}
+
+/// Encode a [`ProfessionalRole`] as the fieldless enum variant `encode_call`
+/// expects; the registry's type id drives the actual on-chain encoding.
+fn role_to_value(role: ProfessionalRole) -> Value<u32> {
+    let variant = match role {
+        ProfessionalRole::Lawyer => "Lawyer",
+        ProfessionalRole::Doctor => "Doctor",
+        ProfessionalRole::Arbitrator => "Arbitrator",
+        ProfessionalRole::Notary => "Notary",
+        ProfessionalRole::Auditor => "Auditor",
+        ProfessionalRole::ConsultantOther => "ConsultantOther",
+    };
+    Value::unnamed_variant(variant, vec![])
 }
 
 #[cfg(test)]
@@ -0,0 +1,106 @@
+//! PSP22 token contract client
+//!
+//! Lets escrow agreements settle milestones in an ink! PSP22 token instead of
+//! the chain's native balance.
+
+use super::gas::{self, ContractCall, GasEstimate};
+use super::metadata::ContractMetadata;
+use super::types::*;
+use anyhow::Result;
+use crate::signer::Signer;
+use scale_value::Value;
+use subxt::{OnlineClient, PolkadotConfig};
+
+/// Client for interacting with a PSP22-compliant token contract
+///
+/// Generic over `C: subxt::Config`, defaulting to [`PolkadotConfig`], so it
+/// can be wired up against a custom GLIN runtime config or a mocked/offline
+/// `OnlineClient<C>` the same way the other contract clients are (see
+/// [`crate::contract_client_scaffold`]).
+///
+/// # Example
+///
+/// ```no_run
+/// use glin_sdk::contracts::Psp22Contract;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = subxt::OnlineClient::<subxt::PolkadotConfig>::new().await?;
+/// let token = Psp22Contract::new(client, "5Token...".parse()?);
+///
+/// let balance = token.balance_of(&"5Holder...".parse()?).await?;
+/// println!("Balance: {balance}");
+/// # Ok(())
+/// # }
+/// ```
+crate::contract_client_scaffold!(Psp22Contract);
+
+impl<C: subxt::Config> Psp22Contract<C>
+where
+    crate::signer::Signer: subxt::tx::Signer<C>,
+{
+    /// Get the token balance of an account
+    pub async fn balance_of(&self, account: &AccountId) -> Result<Balance> {
+        self.query_value(
+            "balance_of",
+            &[Value::from_bytes(account.as_ref())],
+            self.contract_address.clone(),
+            0,
+        )
+        .await
+    }
+
+    /// Get the amount `spender` is allowed to transfer on behalf of `owner`
+    pub async fn allowance(&self, owner: &AccountId, spender: &AccountId) -> Result<Balance> {
+        self.query_value(
+            "allowance",
+            &[Value::from_bytes(owner.as_ref()), Value::from_bytes(spender.as_ref())],
+            self.contract_address.clone(),
+            0,
+        )
+        .await
+    }
+
+    /// Approve `spender` to transfer up to `amount` on the signer's behalf
+    pub async fn approve(
+        &self,
+        spender: &AccountId,
+        amount: Balance,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
+    ) -> Result<ContractResult<()>> {
+        let args = [Value::from_bytes(spender.as_ref()), Value::u128(amount)];
+        let result = self.submit_call("approve", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
+    }
+
+    /// Transfer `amount` from the signer's own balance to `to`
+    pub async fn transfer(
+        &self,
+        to: &AccountId,
+        amount: Balance,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
+    ) -> Result<ContractResult<()>> {
+        let args = [Value::from_bytes(to.as_ref()), Value::u128(amount)];
+        let result = self.submit_call("transfer", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
+    }
+
+    /// Transfer `amount` from `from` to `to`, drawing down the signer's allowance
+    pub async fn transfer_from(
+        &self,
+        from: &AccountId,
+        to: &AccountId,
+        amount: Balance,
+        signer: &Signer,
+        gas_limit: Option<GasLimit>,
+    ) -> Result<ContractResult<()>> {
+        let args = [
+            Value::from_bytes(from.as_ref()),
+            Value::from_bytes(to.as_ref()),
+            Value::u128(amount),
+        ];
+        let result = self.submit_call("transfer_from", &args, 0, signer, gas_limit).await?;
+        Ok(result.map(|_| ()))
+    }
+}
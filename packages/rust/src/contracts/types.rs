@@ -29,6 +29,93 @@ pub struct Milestone {
     pub status: MilestoneStatus,
     pub deadline: Timestamp,
     pub oracle_verification: bool,
+    /// Condition tree that must reduce to [`ReleaseCondition::Paid`] before
+    /// funds can be released
+    pub release_condition: ReleaseCondition,
+}
+
+/// A witness fact that can satisfy part of a [`ReleaseCondition`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Witness {
+    /// The current time has reached (at least) this timestamp
+    Timestamp(Timestamp),
+    /// This account has signed off on release
+    Signature(AccountId),
+}
+
+/// A composable milestone release condition, modeled on the Solana
+/// budget-contract payment plan
+///
+/// Conditions reduce as witnesses are applied via [`apply_witness`], and a
+/// milestone is releasable once its condition has reduced to `Paid`.
+///
+/// [`apply_witness`]: ReleaseCondition::apply_witness
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum ReleaseCondition {
+    /// Already satisfied
+    Paid,
+    /// Satisfied once `AccountId` witnesses a signature
+    Signature(AccountId),
+    /// Satisfied once the clock reaches `Timestamp`, at which point it
+    /// collapses to the inner condition
+    After(Timestamp, Box<ReleaseCondition>),
+    /// Satisfied once both branches are satisfied
+    And(Box<ReleaseCondition>, Box<ReleaseCondition>),
+    /// Satisfied once either branch is satisfied
+    Or(Box<ReleaseCondition>, Box<ReleaseCondition>),
+}
+
+impl ReleaseCondition {
+    /// Whether this condition has fully reduced to `Paid`
+    pub fn is_releasable(&self) -> bool {
+        matches!(self, ReleaseCondition::Paid)
+    }
+
+    /// Apply a witness, collapsing any leaves it satisfies, and return
+    /// whether the condition is now fully releasable
+    pub fn apply_witness(&mut self, witness: Witness) -> bool {
+        match self {
+            ReleaseCondition::Paid => true,
+            ReleaseCondition::Signature(expected) => {
+                if let Witness::Signature(signer) = &witness {
+                    if signer == expected {
+                        *self = ReleaseCondition::Paid;
+                    }
+                }
+                self.is_releasable()
+            }
+            ReleaseCondition::After(deadline, inner) => {
+                if let Witness::Timestamp(now) = &witness {
+                    if now >= deadline {
+                        let mut unlocked = (**inner).clone();
+                        unlocked.apply_witness(witness);
+                        *self = unlocked;
+                    }
+                }
+                self.is_releasable()
+            }
+            ReleaseCondition::And(left, right) => {
+                left.apply_witness(witness.clone());
+                right.apply_witness(witness);
+                if left.is_releasable() && right.is_releasable() {
+                    *self = ReleaseCondition::Paid;
+                }
+                self.is_releasable()
+            }
+            ReleaseCondition::Or(left, right) => {
+                left.apply_witness(witness.clone());
+                if left.is_releasable() {
+                    *self = ReleaseCondition::Paid;
+                    return true;
+                }
+                right.apply_witness(witness);
+                if right.is_releasable() {
+                    *self = ReleaseCondition::Paid;
+                }
+                self.is_releasable()
+            }
+        }
+    }
 }
 
 /// Escrow agreement
@@ -54,6 +141,11 @@ pub struct CreateAgreementParams {
     pub dispute_timeout: Timestamp,
     pub oracle: Option<AccountId>,
     pub value: Balance,
+    /// PSP22 token contract to settle milestones in, instead of native balance
+    pub token_address: Option<AccountId>,
+    /// Per-milestone release condition, parallel to `milestone_descriptions`;
+    /// a `None` entry falls back to the plain oracle/dispute-timeout flow
+    pub milestone_conditions: Vec<Option<ReleaseCondition>>,
 }
 
 // ============================================================================
@@ -83,6 +175,17 @@ pub struct ProfessionalProfile {
     pub registered_at: Timestamp,
     pub is_active: bool,
     pub metadata_uri: String,
+    /// Total stake removed by [`RegistryContract::report_misconduct`]-driven
+    /// slashes so far; see [`effective_stake`](Self::effective_stake)
+    pub slashed_stake: Balance,
+}
+
+impl ProfessionalProfile {
+    /// Stake actually backing this professional's reputation, after
+    /// subtracting everything [`slashed_stake`](Self::slashed_stake) has removed
+    pub fn effective_stake(&self) -> Balance {
+        self.stake_amount.saturating_sub(self.slashed_stake)
+    }
 }
 
 /// Review information
@@ -110,6 +213,77 @@ pub struct SubmitReviewParams {
     pub comment: String,
 }
 
+/// Criteria for narrowing down [`RegistryContract::list_professionals`](super::RegistryContract::list_professionals)
+///
+/// All set fields must match; an all-`None`/`false` filter matches every
+/// registered professional.
+#[derive(Debug, Clone, Default)]
+pub struct ProfessionalFilter {
+    pub role: Option<ProfessionalRole>,
+    pub active_only: bool,
+    pub min_average_rating: Option<f32>,
+    /// Case-sensitive substring match against `metadata_uri`
+    pub metadata_contains: Option<String>,
+}
+
+impl ProfessionalFilter {
+    /// Whether `profile` satisfies every criterion that doesn't require a
+    /// separate review lookup (i.e. everything but `min_average_rating`)
+    pub fn matches_profile(&self, profile: &ProfessionalProfile) -> bool {
+        if let Some(role) = self.role {
+            if profile.role != role {
+                return false;
+            }
+        }
+        if self.active_only && !profile.is_active {
+            return false;
+        }
+        if let Some(substring) = &self.metadata_contains {
+            if !profile.metadata_uri.contains(substring.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// One page of [`RegistryContract::list_professionals`](super::RegistryContract::list_professionals)
+#[derive(Debug, Clone)]
+pub struct ProfessionalPage {
+    pub profiles: Vec<ProfessionalProfile>,
+    /// Pass as the `cursor` argument to fetch the next page; `None` once the
+    /// registry index is exhausted
+    pub next_cursor: Option<u32>,
+}
+
+/// Parameters for [`RegistryContract::report_misconduct`](super::RegistryContract::report_misconduct)
+#[derive(Debug, Clone)]
+pub struct ReportParams {
+    pub professional: AccountId,
+    /// Metadata/IPFS URI pointing at the supporting evidence
+    pub evidence_uri: String,
+    /// Contract-defined code classifying the kind of misconduct (e.g.
+    /// missed deadline, fraud, unprofessional conduct)
+    pub reason_code: u8,
+}
+
+/// A misconduct report filed against a professional
+#[derive(Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+pub struct MisconductReport {
+    pub reporter: AccountId,
+    pub evidence_uri: String,
+    pub reason_code: u8,
+    pub timestamp: Timestamp,
+}
+
+/// One slash applied to a professional's stake after accumulated valid reports
+#[derive(Debug, Clone, Encode, Decode, Serialize, Deserialize)]
+pub struct SlashRecord {
+    pub amount: Balance,
+    pub reason_code: u8,
+    pub timestamp: Timestamp,
+}
+
 // ============================================================================
 // Arbitration Types
 // ============================================================================
@@ -139,6 +313,7 @@ pub struct Dispute {
     pub defendant: AccountId,
     pub description: String,
     pub evidence_uri: String,
+    pub evidence_chain: EvidenceChain,
     pub status: DisputeStatus,
     pub created_at: Timestamp,
     pub voting_ends_at: Timestamp,
@@ -148,6 +323,108 @@ pub struct Dispute {
     pub can_appeal: bool,
 }
 
+// ============================================================================
+// Evidence Chain
+// ============================================================================
+
+/// A blake2-256 digest used throughout the evidence chain
+pub type EvidenceHash = [u8; 32];
+
+/// A single, append-only entry in a dispute's [`EvidenceChain`]
+///
+/// `entry_hash` links each entry to the one before it, so swapping or
+/// reordering evidence after submission changes the hash an arbitrator would
+/// recompute and is therefore detectable by the following entry's
+/// `prev_hash`. The latest entry has no following entry, so
+/// [`EvidenceChain::verify`] does not detect a change to it.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub struct EvidenceEntry {
+    pub uri: String,
+    pub content_hash: EvidenceHash,
+    pub prev_hash: EvidenceHash,
+    pub submitter: AccountId,
+    pub timestamp: Timestamp,
+}
+
+impl EvidenceEntry {
+    /// `blake2_256(prev_hash ++ content_hash ++ submitter ++ timestamp)`
+    pub fn entry_hash(&self) -> EvidenceHash {
+        let mut data = Vec::with_capacity(32 + 32 + self.submitter.as_ref().len() + 8);
+        data.extend_from_slice(&self.prev_hash);
+        data.extend_from_slice(&self.content_hash);
+        data.extend_from_slice(self.submitter.as_ref());
+        data.extend_from_slice(&self.timestamp.to_le_bytes());
+        sp_core::blake2_256(&data)
+    }
+}
+
+/// Tamper-evident, append-only hashchain of evidence for an arbitration dispute
+#[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub struct EvidenceChain {
+    pub entries: Vec<EvidenceEntry>,
+}
+
+impl EvidenceChain {
+    /// The `prev_hash` used by the genesis entry
+    pub const GENESIS_PREV_HASH: EvidenceHash = [0u8; 32];
+
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Build a chain starting from a single genesis entry
+    pub fn genesis(
+        uri: impl Into<String>,
+        content: &[u8],
+        submitter: AccountId,
+        timestamp: Timestamp,
+    ) -> Self {
+        let mut chain = Self::new();
+        chain.append(uri, content, submitter, timestamp);
+        chain
+    }
+
+    /// Append a new entry, hashing `content` and linking it to the current tip
+    pub fn append(
+        &mut self,
+        uri: impl Into<String>,
+        content: &[u8],
+        submitter: AccountId,
+        timestamp: Timestamp,
+    ) -> &EvidenceEntry {
+        let prev_hash = self
+            .entries
+            .last()
+            .map(|e| e.entry_hash())
+            .unwrap_or(Self::GENESIS_PREV_HASH);
+
+        self.entries.push(EvidenceEntry {
+            uri: uri.into(),
+            content_hash: sp_core::blake2_256(content),
+            prev_hash,
+            submitter,
+            timestamp,
+        });
+
+        self.entries.last().unwrap()
+    }
+
+    /// Walk the chain recomputing each `entry_hash` and confirming linkage
+    ///
+    /// Returns `Ok(())` if the chain is intact, or `Err(index)` with the
+    /// index of the first entry whose `prev_hash` doesn't match.
+    pub fn verify(&self) -> Result<(), usize> {
+        let mut expected_prev = Self::GENESIS_PREV_HASH;
+        for (index, entry) in self.entries.iter().enumerate() {
+            if entry.prev_hash != expected_prev {
+                return Err(index);
+            }
+            expected_prev = entry.entry_hash();
+        }
+        Ok(())
+    }
+}
+
 /// Arbitrator information
 #[derive(Debug, Clone, Encode, Decode, Serialize, Deserialize)]
 pub struct Arbitrator {
@@ -165,6 +442,8 @@ pub struct CreateDisputeParams {
     pub defendant: AccountId,
     pub description: String,
     pub evidence_uri: String,
+    /// Genesis entry of the dispute's tamper-evident evidence chain
+    pub initial_evidence: Option<EvidenceEntry>,
 }
 
 /// Parameters for voting on a dispute
@@ -174,6 +453,61 @@ pub struct VoteParams {
     pub choice: VoteChoice,
 }
 
+// ============================================================================
+// Gas Types
+// ============================================================================
+
+/// An explicit gas/weight override for a single extrinsic
+///
+/// Callers can attach this to any contract call to bypass gas estimation
+/// entirely and submit with a known-good weight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasLimit {
+    pub ref_time: u64,
+    pub proof_size: u64,
+    pub storage_deposit_limit: Option<Balance>,
+}
+
+/// How a contract client should determine the gas limit for its extrinsics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasMode {
+    /// Estimate gas per call (the default)
+    Estimate,
+    /// Estimate gas per call, then pad the estimate with `percent` extra
+    /// headroom via [`GasEstimate::with_safety_margin`](super::gas::GasEstimate::with_safety_margin)
+    EstimateWithSafetyMargin(u8),
+    /// Apply the same constant weight to every transaction, skipping estimation
+    Fixed(GasLimit),
+}
+
+impl Default for GasMode {
+    fn default() -> Self {
+        GasMode::Estimate
+    }
+}
+
+impl GasMode {
+    /// Resolve the gas limit to use for a call, given an optional per-call override
+    ///
+    /// Precedence: per-call override, then a client-level fixed mode, then
+    /// `None` to signal that estimation should be performed.
+    pub fn resolve(&self, override_limit: Option<GasLimit>) -> Option<GasLimit> {
+        override_limit.or(match self {
+            GasMode::Estimate | GasMode::EstimateWithSafetyMargin(_) => None,
+            GasMode::Fixed(limit) => Some(*limit),
+        })
+    }
+
+    /// The extra headroom, in percent, to pad a fresh estimate with before
+    /// submitting it, or `None` outside [`GasMode::EstimateWithSafetyMargin`]
+    pub fn safety_margin_percent(&self) -> Option<u8> {
+        match self {
+            GasMode::EstimateWithSafetyMargin(percent) => Some(*percent),
+            _ => None,
+        }
+    }
+}
+
 // ============================================================================
 // Common Types
 // ============================================================================
@@ -205,6 +539,16 @@ impl<T> ContractResult<T> {
             gas_consumed: None,
         }
     }
+
+    /// Transform the success payload, if any, leaving `error`/`gas_consumed` untouched
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ContractResult<U> {
+        ContractResult {
+            success: self.success,
+            data: self.data.map(f),
+            error: self.error,
+            gas_consumed: self.gas_consumed,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -297,6 +641,7 @@ mod tests {
             status: MilestoneStatus::Pending,
             deadline: 1700000000000,
             oracle_verification: false,
+            release_condition: ReleaseCondition::Paid,
         };
 
         assert_eq!(milestone.description, "Test milestone");
@@ -318,6 +663,8 @@ mod tests {
             dispute_timeout: 1700172800,
             oracle: None,
             value: 3000,
+            token_address: None,
+            milestone_conditions: vec![None, None],
         };
 
         assert_eq!(params.provider, provider);
@@ -341,12 +688,34 @@ mod tests {
             registered_at: 1700000000,
             is_active: true,
             metadata_uri: "ipfs://test".to_string(),
+            slashed_stake: 0,
         };
 
         assert_eq!(profile.account, account);
         assert_eq!(profile.role, ProfessionalRole::Lawyer);
         assert!(profile.is_active);
         assert_eq!(profile.total_jobs, 0);
+        assert_eq!(profile.effective_stake(), profile.stake_amount);
+    }
+
+    #[test]
+    fn test_effective_stake_subtracts_slashes() {
+        use sp_core::crypto::AccountId32;
+
+        let profile = ProfessionalProfile {
+            account: AccountId32::new([2u8; 32]),
+            role: ProfessionalRole::Lawyer,
+            stake_amount: 100,
+            reputation_score: 100,
+            total_jobs: 0,
+            successful_jobs: 0,
+            registered_at: 1700000000,
+            is_active: true,
+            metadata_uri: "ipfs://test".to_string(),
+            slashed_stake: 40,
+        };
+
+        assert_eq!(profile.effective_stake(), 60);
     }
 
     #[test]
@@ -379,6 +748,7 @@ mod tests {
             defendant: defendant.clone(),
             description: "Service not delivered".to_string(),
             evidence_uri: "ipfs://evidence".to_string(),
+            evidence_chain: EvidenceChain::new(),
             status: DisputeStatus::Open,
             created_at: 1700000000,
             voting_ends_at: 1700604800,
@@ -395,6 +765,42 @@ mod tests {
         assert!(dispute.resolution.is_none());
     }
 
+    #[test]
+    fn test_gas_mode_resolve_precedence() {
+        let fixed = GasLimit {
+            ref_time: 1_000_000,
+            proof_size: 10_000,
+            storage_deposit_limit: Some(500),
+        };
+        let override_limit = GasLimit {
+            ref_time: 2_000_000,
+            proof_size: 20_000,
+            storage_deposit_limit: None,
+        };
+
+        assert_eq!(GasMode::Estimate.resolve(None), None);
+        assert_eq!(GasMode::Estimate.resolve(Some(override_limit)), Some(override_limit));
+        assert_eq!(GasMode::Fixed(fixed).resolve(None), Some(fixed));
+        assert_eq!(
+            GasMode::Fixed(fixed).resolve(Some(override_limit)),
+            Some(override_limit)
+        );
+    }
+
+    #[test]
+    fn test_gas_mode_safety_margin_percent() {
+        assert_eq!(GasMode::Estimate.safety_margin_percent(), None);
+        assert_eq!(GasMode::EstimateWithSafetyMargin(20).safety_margin_percent(), Some(20));
+        assert_eq!(
+            GasMode::Fixed(GasLimit { ref_time: 0, proof_size: 0, storage_deposit_limit: None })
+                .safety_margin_percent(),
+            None
+        );
+
+        // Still defers to a dry-run estimate, same as plain `Estimate`
+        assert_eq!(GasMode::EstimateWithSafetyMargin(20).resolve(None), None);
+    }
+
     #[test]
     fn test_arbitrator_struct() {
         use sp_core::crypto::AccountId32;
@@ -414,4 +820,91 @@ mod tests {
         assert!(arbitrator.is_active);
         assert_eq!(arbitrator.reputation, 100);
     }
+
+    #[test]
+    fn test_evidence_chain_links_entries() {
+        use sp_core::crypto::AccountId32;
+
+        let submitter = AccountId32::new([7u8; 32]);
+        let mut chain = EvidenceChain::genesis("ipfs://genesis", b"first evidence", submitter.clone(), 1700000000);
+        chain.append("ipfs://followup", b"second evidence", submitter, 1700000100);
+
+        assert_eq!(chain.entries.len(), 2);
+        assert_eq!(chain.entries[0].prev_hash, EvidenceChain::GENESIS_PREV_HASH);
+        assert_eq!(chain.entries[1].prev_hash, chain.entries[0].entry_hash());
+        assert_eq!(chain.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_release_condition_or_collapses_to_first_paid_branch() {
+        use sp_core::crypto::AccountId32;
+
+        let client = AccountId32::new([9u8; 32]);
+        let oracle = AccountId32::new([10u8; 32]);
+
+        let mut condition = ReleaseCondition::Or(
+            Box::new(ReleaseCondition::Signature(client.clone())),
+            Box::new(ReleaseCondition::Signature(oracle.clone())),
+        );
+
+        assert!(!condition.apply_witness(Witness::Signature(AccountId32::new([99u8; 32]))));
+        assert!(condition.apply_witness(Witness::Signature(oracle)));
+        assert_eq!(condition, ReleaseCondition::Paid);
+    }
+
+    #[test]
+    fn test_release_condition_and_requires_both_branches() {
+        use sp_core::crypto::AccountId32;
+
+        let client = AccountId32::new([11u8; 32]);
+        let provider = AccountId32::new([12u8; 32]);
+
+        let mut condition = ReleaseCondition::And(
+            Box::new(ReleaseCondition::Signature(client.clone())),
+            Box::new(ReleaseCondition::Signature(provider.clone())),
+        );
+
+        assert!(!condition.apply_witness(Witness::Signature(client)));
+        assert!(condition.apply_witness(Witness::Signature(provider)));
+        assert_eq!(condition, ReleaseCondition::Paid);
+    }
+
+    #[test]
+    fn test_release_condition_after_unlocks_inner_on_deadline() {
+        use sp_core::crypto::AccountId32;
+
+        let provider = AccountId32::new([13u8; 32]);
+        let mut condition = ReleaseCondition::After(
+            1700000000,
+            Box::new(ReleaseCondition::Signature(provider.clone())),
+        );
+
+        // Too early: the inner signature condition stays locked
+        assert!(!condition.apply_witness(Witness::Timestamp(1699999999)));
+        assert_eq!(
+            condition,
+            ReleaseCondition::After(1700000000, Box::new(ReleaseCondition::Signature(provider.clone())))
+        );
+
+        // Deadline reached: collapses to the inner condition
+        assert!(!condition.apply_witness(Witness::Timestamp(1700000000)));
+        assert_eq!(condition, ReleaseCondition::Signature(provider.clone()));
+
+        assert!(condition.apply_witness(Witness::Signature(provider)));
+        assert_eq!(condition, ReleaseCondition::Paid);
+    }
+
+    #[test]
+    fn test_evidence_chain_detects_tampering() {
+        use sp_core::crypto::AccountId32;
+
+        let submitter = AccountId32::new([8u8; 32]);
+        let mut chain = EvidenceChain::genesis("ipfs://genesis", b"first evidence", submitter.clone(), 1700000000);
+        chain.append("ipfs://followup", b"second evidence", submitter, 1700000100);
+
+        // Tamper with the genesis entry's content after the fact
+        chain.entries[0].content_hash = sp_core::blake2_256(b"swapped evidence");
+
+        assert_eq!(chain.verify(), Err(1));
+    }
 }
@@ -0,0 +1,203 @@
+//! Key management: keypair generation, mnemonic derivation, and vanity address search
+//!
+//! This mirrors the key-handling commands of the standard Substrate `ethkey` CLI
+//! (`generate`, `prefix`, `recover`) but produces GLIN SS58 keypairs that can be
+//! used directly with the rest of the SDK (e.g. signing contract calls).
+//!
+//! `KeyManager` is sr25519-only, which is all vanity search and word-level
+//! mnemonic recovery need. For ed25519/ecdsa keys, or for passing a keypair
+//! straight into a contract call, use [`crate::signer::Signer`] instead.
+
+use anyhow::{anyhow, Result};
+use sp_core::{crypto::Ss58Codec, sr25519::Pair as Sr25519Pair, Pair};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A keypair generated or derived by [`KeyManager`], along with its mnemonic
+/// (when one was used or generated).
+pub struct GeneratedKey {
+    pub pair: Sr25519Pair,
+    pub mnemonic: Option<String>,
+}
+
+/// Options controlling a vanity address search
+#[derive(Debug, Clone)]
+pub struct VanityOptions {
+    /// Number of worker threads to search across
+    pub threads: usize,
+    /// Match the prefix case-insensitively
+    pub case_insensitive: bool,
+    /// Give up after this many total attempts across all workers
+    pub max_attempts: Option<u64>,
+}
+
+impl Default for VanityOptions {
+    fn default() -> Self {
+        Self {
+            threads: 4,
+            case_insensitive: false,
+            max_attempts: None,
+        }
+    }
+}
+
+/// Result of a successful vanity address search
+pub struct VanityMatch {
+    pub pair: Sr25519Pair,
+    pub attempts: u64,
+}
+
+/// A mnemonic recovery request: some words are known, others are missing or
+/// suspected to be misspelled
+pub struct RecoveryRequest {
+    /// One entry per word position; `None` marks an unknown/misspelled word
+    pub words: Vec<Option<String>>,
+    /// Total number of words in the phrase (12, 15, 18, 21, or 24)
+    pub word_count: usize,
+    /// The SS58 address the recovered phrase must derive
+    pub expected_address: String,
+    /// Optional derivation password
+    pub password: Option<String>,
+}
+
+/// Key management utilities: generation, mnemonic derivation, vanity search, and recovery
+pub struct KeyManager;
+
+impl KeyManager {
+    /// Generate a random sr25519 keypair with a fresh BIP39 mnemonic
+    pub fn generate() -> GeneratedKey {
+        let (pair, mnemonic, _) = Sr25519Pair::generate_with_phrase(None);
+        GeneratedKey {
+            pair,
+            mnemonic: Some(mnemonic),
+        }
+    }
+
+    /// Derive a keypair from a BIP39/brain mnemonic phrase, with an optional password
+    pub fn from_mnemonic(phrase: &str, password: Option<&str>) -> Result<Sr25519Pair> {
+        let (pair, _) = Sr25519Pair::from_phrase(phrase, password)
+            .map_err(|_| anyhow!("invalid mnemonic phrase"))?;
+        Ok(pair)
+    }
+
+    /// Search for a keypair whose SS58 address begins with `prefix`
+    ///
+    /// The search runs across `options.threads` worker threads and stops as soon
+    /// as any worker finds a match (or `options.max_attempts` is exhausted).
+    pub fn generate_vanity(prefix: &str, options: VanityOptions) -> Result<VanityMatch> {
+        let prefix = if options.case_insensitive {
+            prefix.to_lowercase()
+        } else {
+            prefix.to_string()
+        };
+
+        let found: Arc<Mutex<Option<VanityMatch>>> = Arc::new(Mutex::new(None));
+        let attempts = Arc::new(AtomicU64::new(0));
+        let stop = Arc::new(AtomicBool::new(false));
+        let threads = options.threads.max(1);
+
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                let found = Arc::clone(&found);
+                let attempts = Arc::clone(&attempts);
+                let stop = Arc::clone(&stop);
+                let prefix = prefix.clone();
+                let case_insensitive = options.case_insensitive;
+                let max_attempts = options.max_attempts;
+
+                scope.spawn(move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        let (pair, _) = Sr25519Pair::generate();
+                        let n = attempts.fetch_add(1, Ordering::Relaxed) + 1;
+
+                        let address = pair.public().to_ss58check();
+                        let matches = if case_insensitive {
+                            address.to_lowercase().starts_with(&prefix)
+                        } else {
+                            address.starts_with(&prefix)
+                        };
+
+                        if matches {
+                            *found.lock().unwrap() = Some(VanityMatch { pair, attempts: n });
+                            stop.store(true, Ordering::Relaxed);
+                            break;
+                        }
+
+                        if let Some(max) = max_attempts {
+                            if n >= max {
+                                stop.store(true, Ordering::Relaxed);
+                                break;
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        Arc::try_unwrap(found)
+            .map_err(|_| anyhow!("vanity search threads did not shut down cleanly"))?
+            .into_inner()
+            .map_err(|_| anyhow!("vanity search result lock was poisoned"))?
+            .ok_or_else(|| anyhow!("no vanity address found within the attempt cap"))
+    }
+
+    /// Recover a mnemonic phrase when some words are missing or misspelled
+    ///
+    /// Brute-forces every BIP39 wordlist candidate for each unknown position
+    /// until the derived account address matches `request.expected_address`.
+    pub fn recover_mnemonic(request: &RecoveryRequest) -> Result<String> {
+        if request.words.len() != request.word_count {
+            return Err(anyhow!(
+                "expected {} words, got {}",
+                request.word_count,
+                request.words.len()
+            ));
+        }
+
+        let unknown: Vec<usize> = request
+            .words
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| w.is_none())
+            .map(|(i, _)| i)
+            .collect();
+
+        let wordlist = bip39::Language::English.word_list();
+        let mut working: Vec<String> = request
+            .words
+            .iter()
+            .map(|w| w.clone().unwrap_or_default())
+            .collect();
+
+        Self::search_words(&mut working, &unknown, 0, wordlist, request)
+            .ok_or_else(|| anyhow!("no candidate phrase matched the expected address"))
+    }
+
+    fn search_words(
+        working: &mut Vec<String>,
+        unknown: &[usize],
+        depth: usize,
+        wordlist: &[&str],
+        request: &RecoveryRequest,
+    ) -> Option<String> {
+        if depth == unknown.len() {
+            let phrase = working.join(" ");
+            let (pair, _) = Sr25519Pair::from_phrase(&phrase, request.password.as_deref()).ok()?;
+            if pair.public().to_ss58check() == request.expected_address {
+                return Some(phrase);
+            }
+            return None;
+        }
+
+        let position = unknown[depth];
+        for word in wordlist {
+            working[position] = word.to_string();
+            if let Some(phrase) = Self::search_words(working, unknown, depth + 1, wordlist, request) {
+                return Some(phrase);
+            }
+        }
+
+        None
+    }
+}
@@ -35,11 +35,12 @@
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     // Initialize contracts
-//!     let contracts = GlinContracts::new(
+//!     let contracts = GlinContracts::<subxt::PolkadotConfig>::new(
 //!         "wss://rpc.glin.ai",
 //!         Some("5Escrow...".parse()?),
 //!         Some("5Registry...".parse()?),
 //!         Some("5Arbitration...".parse()?),
+//!         None,
 //!     ).await?;
 //!
 //!     // Create escrow agreement
@@ -51,6 +52,8 @@
 //!         dispute_timeout: 1234567890,
 //!         oracle: None,
 //!         value: 2_000_000_000_000_000_000_000,
+//!         token_address: None,
+//!         milestone_conditions: vec![None, None],
 //!     };
 //!
 //!     // let result = contracts.escrow.create_agreement(params, &keypair).await?;
@@ -61,6 +64,9 @@
 
 pub mod client;
 pub mod auth;
+pub mod keys;
+pub mod retry;
+pub mod signer;
 pub mod types;
 
 // Smart Contracts
@@ -68,6 +74,9 @@ pub mod contracts;
 
 pub use client::GlinClient;
 pub use auth::GlinAuth;
+pub use keys::KeyManager;
+pub use retry::{ConnectionState, RetryPolicy};
+pub use signer::{Public, Scheme, Signature, Signer};
 pub use types::*;
 
 // Re-export contracts module
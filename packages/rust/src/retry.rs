@@ -0,0 +1,98 @@
+//! Retry and reconnection policy used by [`crate::GlinClient`]
+//!
+//! Long-running daemons (arbitrators, providers) hold a `GlinClient` open for
+//! hours or days; a dropped WebSocket or a transient RPC hiccup shouldn't kill
+//! the whole process. [`RetryPolicy`] classifies failures as transient
+//! (worth retrying, with exponential backoff) or permanent (retrying won't help).
+
+use std::time::Duration;
+
+/// Whether a failure is worth retrying
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// Network/timeout/connection-loss style failure
+    Transient,
+    /// Bad signature, invalid params, or another failure retrying can't fix
+    Permanent,
+}
+
+/// Observed connection state, reported to callbacks registered via
+/// [`crate::GlinClient::on_connection_state_change`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Connected,
+    Reconnecting,
+    Disconnected,
+}
+
+/// Exponential backoff policy for reconnecting and retrying RPC calls
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound on the backoff delay, regardless of attempt count
+    pub max_delay: Duration,
+    /// Give up after this many attempts
+    pub max_attempts: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(30),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The backoff delay before the given (1-indexed) attempt
+    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let multiplier = 1u64.checked_shl(attempt.saturating_sub(1)).unwrap_or(u64::MAX);
+        let scaled_ms = (self.base_delay.as_millis() as u64).saturating_mul(multiplier);
+        Duration::from_millis(scaled_ms).min(self.max_delay)
+    }
+
+    /// Classify a subxt error as transient (network/timeout) or permanent
+    /// (bad signature, invalid params)
+    ///
+    /// Current `subxt` releases fold connection/transport failures into
+    /// `Error::Rpc(RpcError)` rather than exposing a separate `Error::Transport`
+    /// variant, so that's the only variant matched here.
+    pub fn classify(&self, error: &subxt::Error) -> FailureKind {
+        match error {
+            subxt::Error::Rpc(_) => FailureKind::Transient,
+            _ => FailureKind::Permanent,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_for_attempt_backs_off_exponentially() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            max_attempts: 10,
+        };
+
+        assert_eq!(policy.delay_for_attempt(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for_attempt(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_for_attempt(3), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_delay_for_attempt_caps_at_max_delay() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            max_attempts: 10,
+        };
+
+        assert_eq!(policy.delay_for_attempt(20), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,247 @@
+//! Multi-scheme transaction signer: sr25519, ed25519, and ecdsa keypairs behind one type
+//!
+//! Contract methods used to hardcode `&sp_core::sr25519::Pair` as their signer
+//! parameter, so callers who only had an ed25519 or ecdsa key (e.g. recovered
+//! through an external wallet) couldn't use the SDK at all. [`Signer`] wraps
+//! all three schemes `sp_core` supports and implements `subxt::tx::Signer`
+//! directly, so it can be handed straight to `sign_and_submit_then_watch_default`
+//! in place of `subxt::tx::PairSigner`'s single-scheme wrapper.
+//!
+//! This mirrors the key-handling commands of the standard Substrate `ethkey`
+//! CLI (`generate`, `recover`, `verify`) across all three schemes, alongside
+//! [`crate::keys::KeyManager`] which stays sr25519-only for vanity search and
+//! word-level mnemonic recovery.
+
+use anyhow::{anyhow, Result};
+use sp_core::{blake2_256, crypto::Ss58Codec, ecdsa, ed25519, sr25519, Pair as PairT};
+
+/// Which elliptic-curve scheme a [`Signer`] was built from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    Sr25519,
+    Ed25519,
+    Ecdsa,
+}
+
+/// A keypair in one of the three schemes `sp_core`/Substrate support
+#[derive(Clone)]
+pub enum Signer {
+    Sr25519(sr25519::Pair),
+    Ed25519(ed25519::Pair),
+    Ecdsa(ecdsa::Pair),
+}
+
+impl From<sr25519::Pair> for Signer {
+    /// Wrap a bare sr25519 keypair (e.g. from `sp_keyring::AccountKeyring::pair`)
+    /// as a [`Signer`], for callers migrating off the old single-scheme signer
+    /// parameter without having to go through [`Signer::from_seed`]/[`Signer::from_suri`]
+    fn from(pair: sr25519::Pair) -> Self {
+        Signer::Sr25519(pair)
+    }
+}
+
+/// A public key in one of the three schemes `sp_core`/Substrate support
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Public {
+    Sr25519(sr25519::Public),
+    Ed25519(ed25519::Public),
+    Ecdsa(ecdsa::Public),
+}
+
+/// A signature in one of the three schemes `sp_core`/Substrate support
+#[derive(Debug, Clone)]
+pub enum Signature {
+    Sr25519(sr25519::Signature),
+    Ed25519(ed25519::Signature),
+    Ecdsa(ecdsa::Signature),
+}
+
+/// Raw account id bytes this keypair/public key controls; for sr25519/ed25519
+/// this is the public key itself, for ecdsa (whose public key is a 33-byte
+/// compressed point) it's `blake2_256(public_key)`, matching Substrate's
+/// `MultiSigner::into_account` convention.
+fn account_id_bytes(public: &Public) -> [u8; 32] {
+    match public {
+        Public::Sr25519(public) => public.0,
+        Public::Ed25519(public) => public.0,
+        Public::Ecdsa(public) => blake2_256(public.as_ref()),
+    }
+}
+
+impl Signer {
+    /// Generate a random keypair with a fresh BIP39 mnemonic
+    pub fn generate(scheme: Scheme) -> (Self, String) {
+        match scheme {
+            Scheme::Sr25519 => {
+                let (pair, phrase, _) = sr25519::Pair::generate_with_phrase(None);
+                (Signer::Sr25519(pair), phrase)
+            }
+            Scheme::Ed25519 => {
+                let (pair, phrase, _) = ed25519::Pair::generate_with_phrase(None);
+                (Signer::Ed25519(pair), phrase)
+            }
+            Scheme::Ecdsa => {
+                let (pair, phrase, _) = ecdsa::Pair::generate_with_phrase(None);
+                (Signer::Ecdsa(pair), phrase)
+            }
+        }
+    }
+
+    /// Derive a keypair from a BIP39/brain mnemonic phrase, with an optional password
+    pub fn from_mnemonic(scheme: Scheme, phrase: &str, password: Option<&str>) -> Result<Self> {
+        match scheme {
+            Scheme::Sr25519 => sr25519::Pair::from_phrase(phrase, password)
+                .map(|(pair, _)| Signer::Sr25519(pair))
+                .map_err(|_| anyhow!("invalid sr25519 mnemonic phrase")),
+            Scheme::Ed25519 => ed25519::Pair::from_phrase(phrase, password)
+                .map(|(pair, _)| Signer::Ed25519(pair))
+                .map_err(|_| anyhow!("invalid ed25519 mnemonic phrase")),
+            Scheme::Ecdsa => ecdsa::Pair::from_phrase(phrase, password)
+                .map(|(pair, _)| Signer::Ecdsa(pair))
+                .map_err(|_| anyhow!("invalid ecdsa mnemonic phrase")),
+        }
+    }
+
+    /// Derive a keypair from a raw seed, with no derivation path applied
+    pub fn from_seed(scheme: Scheme, seed: &[u8]) -> Result<Self> {
+        match scheme {
+            Scheme::Sr25519 => sr25519::Pair::from_seed_slice(seed)
+                .map(Signer::Sr25519)
+                .map_err(|_| anyhow!("invalid sr25519 seed")),
+            Scheme::Ed25519 => ed25519::Pair::from_seed_slice(seed)
+                .map(Signer::Ed25519)
+                .map_err(|_| anyhow!("invalid ed25519 seed")),
+            Scheme::Ecdsa => ecdsa::Pair::from_seed_slice(seed)
+                .map(Signer::Ecdsa)
+                .map_err(|_| anyhow!("invalid ecdsa seed")),
+        }
+    }
+
+    /// Derive a keypair from a full SURI: a mnemonic or raw seed, optionally
+    /// followed by `//hard/soft` derivation junctions and a `///password`
+    /// (e.g. `"//Alice"`, `"<mnemonic phrase>//0/1"`)
+    pub fn from_suri(scheme: Scheme, suri: &str, password: Option<&str>) -> Result<Self> {
+        match scheme {
+            Scheme::Sr25519 => sr25519::Pair::from_string(suri, password)
+                .map(Signer::Sr25519)
+                .map_err(|error| anyhow!("invalid sr25519 SURI: {error:?}")),
+            Scheme::Ed25519 => ed25519::Pair::from_string(suri, password)
+                .map(Signer::Ed25519)
+                .map_err(|error| anyhow!("invalid ed25519 SURI: {error:?}")),
+            Scheme::Ecdsa => ecdsa::Pair::from_string(suri, password)
+                .map(Signer::Ecdsa)
+                .map_err(|error| anyhow!("invalid ecdsa SURI: {error:?}")),
+        }
+    }
+
+    /// This keypair's scheme
+    pub fn scheme(&self) -> Scheme {
+        match self {
+            Signer::Sr25519(_) => Scheme::Sr25519,
+            Signer::Ed25519(_) => Scheme::Ed25519,
+            Signer::Ecdsa(_) => Scheme::Ecdsa,
+        }
+    }
+
+    /// This keypair's public key
+    pub fn public(&self) -> Public {
+        match self {
+            Signer::Sr25519(pair) => Public::Sr25519(pair.public()),
+            Signer::Ed25519(pair) => Public::Ed25519(pair.public()),
+            Signer::Ecdsa(pair) => Public::Ecdsa(pair.public()),
+        }
+    }
+
+    /// The account id this keypair controls, as used throughout the
+    /// `contracts` module
+    pub fn contract_account_id(&self) -> crate::contracts::AccountId {
+        crate::contracts::AccountId::from(account_id_bytes(&self.public()))
+    }
+
+    /// SS58-encode this keypair's account id
+    pub fn to_ss58check(&self) -> String {
+        self.contract_account_id().to_ss58check()
+    }
+
+    /// Sign `message`, tagging the signature with this keypair's scheme
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        match self {
+            Signer::Sr25519(pair) => Signature::Sr25519(pair.sign(message)),
+            Signer::Ed25519(pair) => Signature::Ed25519(pair.sign(message)),
+            Signer::Ecdsa(pair) => Signature::Ecdsa(pair.sign(message)),
+        }
+    }
+}
+
+/// Verify `signature` over `message` against a known public key, without
+/// needing the keypair that produced it
+pub fn verify(signature: &Signature, message: &[u8], public: &Public) -> bool {
+    match (signature, public) {
+        (Signature::Sr25519(signature), Public::Sr25519(public)) => {
+            sr25519::Pair::verify(signature, message, public)
+        }
+        (Signature::Ed25519(signature), Public::Ed25519(public)) => {
+            ed25519::Pair::verify(signature, message, public)
+        }
+        (Signature::Ecdsa(signature), Public::Ecdsa(public)) => {
+            ecdsa::Pair::verify(signature, message, public)
+        }
+        _ => false,
+    }
+}
+
+/// Verify `signature` over `message` against an SS58-encoded account `address`
+///
+/// Only works for sr25519/ed25519, whose account id *is* the public key; an
+/// ecdsa account id is a hash of its public key, so verifying against an
+/// ecdsa address requires the public key itself, not just the address — use
+/// [`verify`] with `Public::Ecdsa` instead.
+pub fn verify_against_address(signature: &Signature, message: &[u8], address: &str) -> Result<bool> {
+    let account = sp_core::crypto::AccountId32::from_ss58check(address)
+        .map_err(|error| anyhow!("'{address}' is not a valid SS58 address: {error:?}"))?;
+    let raw: [u8; 32] = *account.as_ref();
+
+    let public = match signature {
+        Signature::Sr25519(_) => Public::Sr25519(sr25519::Public::from_raw(raw)),
+        Signature::Ed25519(_) => Public::Ed25519(ed25519::Public::from_raw(raw)),
+        Signature::Ecdsa(_) => {
+            return Err(anyhow!(
+                "ecdsa account ids are a hash of the public key, not the key itself; verify against the public key with `verify`"
+            ))
+        }
+    };
+
+    Ok(verify(signature, message, &public))
+}
+
+/// Usable against any chain config that keeps the standard Substrate
+/// account/address/signature shapes (`PolkadotConfig`, `SubstrateConfig`, and
+/// most custom GLIN runtime configs that only change `Hash`) — not just
+/// `PolkadotConfig` — so the same `Signer` works unmodified against those
+/// chains' contract clients (see [`crate::contract_client_scaffold`]).
+/// A config with a genuinely different `AccountId`/`Signature` encoding needs
+/// its own `subxt::tx::Signer` adapter instead.
+impl<C> subxt::tx::Signer<C> for Signer
+where
+    C: subxt::Config<
+        AccountId = subxt::utils::AccountId32,
+        Address = subxt::utils::MultiAddress<subxt::utils::AccountId32, ()>,
+        Signature = subxt::utils::MultiSignature,
+    >,
+{
+    fn account_id(&self) -> C::AccountId {
+        subxt::utils::AccountId32::from(account_id_bytes(&self.public()))
+    }
+
+    fn address(&self) -> C::Address {
+        subxt::utils::MultiAddress::Id(subxt::tx::Signer::<C>::account_id(self))
+    }
+
+    fn sign(&self, signer_payload: &[u8]) -> C::Signature {
+        match self {
+            Signer::Sr25519(pair) => subxt::utils::MultiSignature::Sr25519(pair.sign(signer_payload).0),
+            Signer::Ed25519(pair) => subxt::utils::MultiSignature::Ed25519(pair.sign(signer_payload).0),
+            Signer::Ecdsa(pair) => subxt::utils::MultiSignature::Ecdsa(pair.sign(signer_payload).0),
+        }
+    }
+}
@@ -3,6 +3,9 @@
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
+/// A block hash, as used to pin storage queries to a specific block
+pub type BlockHash = subxt::utils::H256;
+
 /// Account balance information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Balance {
@@ -15,7 +15,7 @@ use glin_sdk::contracts::{
     GlinContracts, MilestoneStatus, ProfessionalRole, RegisterProfessionalParams,
     RegistryContract, SubmitReviewParams, VoteChoice, VoteParams,
 };
-use sp_core::{crypto::Ss25519, sr25519::Pair, Pair as PairTrait};
+use glin_sdk::signer::Signer;
 use sp_keyring::AccountKeyring;
 use std::env;
 use subxt::{OnlineClient, PolkadotConfig};
@@ -63,7 +63,7 @@ async fn test_escrow_create_agreement() {
         .await
         .expect("Failed to connect");
 
-    let alice = AccountKeyring::Alice.pair();
+    let alice: Signer = AccountKeyring::Alice.pair().into();
     let bob = AccountKeyring::Bob;
 
     let contract = EscrowContract::new(client, escrow_addr.unwrap().parse().unwrap());
@@ -81,9 +81,11 @@ async fn test_escrow_create_agreement() {
         dispute_timeout: now + 259200000,
         oracle: None,
         value: 1_000_000_000_000_000_000_000,
+        token_address: None,
+        milestone_conditions: vec![None],
     };
 
-    let result = contract.create_agreement(params, &alice).await;
+    let result = contract.create_agreement(params, &alice, None).await;
 
     assert!(result.is_ok());
     let contract_result = result.unwrap();
@@ -144,7 +146,7 @@ async fn test_registry_register_professional() {
         .await
         .expect("Failed to connect");
 
-    let alice = AccountKeyring::Alice.pair();
+    let alice: Signer = AccountKeyring::Alice.pair().into();
 
     let contract = RegistryContract::new(client, registry_addr.unwrap().parse().unwrap());
 
@@ -154,7 +156,7 @@ async fn test_registry_register_professional() {
         stake_amount: 100_000_000_000_000_000_000,
     };
 
-    let result = contract.register(params, &alice).await;
+    let result = contract.register(params, &alice, None).await;
 
     // May fail if already registered, which is okay
     assert!(result.is_ok() || result.is_err());
@@ -177,7 +179,7 @@ async fn test_registry_query_profile() {
 
     let contract = RegistryContract::new(client, registry_addr.unwrap().parse().unwrap());
 
-    let profile = contract.get_profile(alice.into()).await;
+    let profile = contract.get_profile(&alice.into()).await;
 
     assert!(profile.is_ok());
 }
@@ -218,12 +220,12 @@ async fn test_arbitration_register_arbitrator() {
         .await
         .expect("Failed to connect");
 
-    let alice = AccountKeyring::Alice.pair();
+    let alice: Signer = AccountKeyring::Alice.pair().into();
 
     let contract = ArbitrationContract::new(client, arbitration_addr.unwrap().parse().unwrap());
 
     let result = contract
-        .register_arbitrator(200_000_000_000_000_000_000, &alice)
+        .register_arbitrator(200_000_000_000_000_000_000, &alice, None)
         .await;
 
     // May fail if already registered
@@ -243,7 +245,7 @@ async fn test_arbitration_create_dispute() {
         .await
         .expect("Failed to connect");
 
-    let alice = AccountKeyring::Alice.pair();
+    let alice: Signer = AccountKeyring::Alice.pair().into();
     let bob = AccountKeyring::Bob;
 
     let contract = ArbitrationContract::new(client, arbitration_addr.unwrap().parse().unwrap());
@@ -252,9 +254,10 @@ async fn test_arbitration_create_dispute() {
         defendant: bob.to_account_id().into(),
         description: "Test dispute".to_string(),
         evidence_uri: "ipfs://evidence".to_string(),
+        initial_evidence: None,
     };
 
-    let result = contract.create_dispute(params, &alice).await;
+    let result = contract.create_dispute(params, &alice, None).await;
 
     assert!(result.is_ok());
     let contract_result = result.unwrap();
@@ -278,7 +281,7 @@ async fn test_arbitration_query_arbitrator() {
 
     let contract = ArbitrationContract::new(client, arbitration_addr.unwrap().parse().unwrap());
 
-    let arbitrator = contract.get_arbitrator(alice.into()).await;
+    let arbitrator = contract.get_arbitrator(&alice.into()).await;
 
     assert!(arbitrator.is_ok());
 }
@@ -316,30 +319,22 @@ async fn test_glin_contracts_wrapper() {
         .await
         .expect("Failed to connect");
 
-    let alice = AccountKeyring::Alice.pair();
-
-    let contracts = GlinContracts::new(
+    let contracts = GlinContracts::from_client(
         client,
-        escrow_addr.unwrap().parse().unwrap(),
+        Some(escrow_addr.unwrap().parse().unwrap()),
         registry_addr.map(|a| a.parse().unwrap()),
         arbitration_addr.map(|a| a.parse().unwrap()),
+        None,
     );
 
     // Test that all contracts are accessible
     assert!(contracts.escrow.get_agreement(0).await.is_ok());
-
-    if contracts.registry.is_some() {
-        let registry = contracts.registry.as_ref().unwrap();
-        assert!(registry
-            .get_profile(AccountKeyring::Alice.to_account_id().into())
-            .await
-            .is_ok());
-    }
-
-    if contracts.arbitration.is_some() {
-        let arbitration = contracts.arbitration.as_ref().unwrap();
-        assert!(arbitration.get_dispute(0).await.is_ok());
-    }
+    assert!(contracts
+        .registry
+        .get_profile(&AccountKeyring::Alice.to_account_id().into())
+        .await
+        .is_ok());
+    assert!(contracts.arbitration.get_dispute(0).await.is_ok());
 }
 
 #[tokio::test]
@@ -355,7 +350,7 @@ async fn test_complete_escrow_workflow() {
         .await
         .expect("Failed to connect");
 
-    let alice = AccountKeyring::Alice.pair();
+    let alice: Signer = AccountKeyring::Alice.pair().into();
     let bob = AccountKeyring::Bob;
 
     let contract = EscrowContract::new(client, escrow_addr.unwrap().parse().unwrap());
@@ -374,9 +369,11 @@ async fn test_complete_escrow_workflow() {
         dispute_timeout: now + 259200000,
         oracle: None,
         value: 3_000_000_000_000_000_000_000,
+        token_address: None,
+        milestone_conditions: vec![None, None],
     };
 
-    let create_result = contract.create_agreement(params, &alice).await;
+    let create_result = contract.create_agreement(params, &alice, None).await;
     assert!(create_result.is_ok());
 
     let contract_result = create_result.unwrap();
@@ -413,7 +410,7 @@ async fn test_complete_registry_workflow() {
         .await
         .expect("Failed to connect");
 
-    let alice = AccountKeyring::Alice.pair();
+    let alice: Signer = AccountKeyring::Alice.pair().into();
 
     let contract = RegistryContract::new(client, registry_addr.unwrap().parse().unwrap());
 
@@ -431,11 +428,11 @@ async fn test_complete_registry_workflow() {
         stake_amount: 150_000_000_000_000_000_000,
     };
 
-    let _register_result = contract.register(params, &alice).await;
+    let _register_result = contract.register(params, &alice, None).await;
 
     // 3. Query profile
     let profile = contract
-        .get_profile(AccountKeyring::Alice.to_account_id().into())
+        .get_profile(&AccountKeyring::Alice.to_account_id().into())
         .await;
     assert!(profile.is_ok());
 }
@@ -453,19 +450,19 @@ async fn test_complete_arbitration_workflow() {
         .await
         .expect("Failed to connect");
 
-    let alice = AccountKeyring::Alice.pair();
+    let alice: Signer = AccountKeyring::Alice.pair().into();
     let bob = AccountKeyring::Bob;
 
     let contract = ArbitrationContract::new(client, arbitration_addr.unwrap().parse().unwrap());
 
     // 1. Register as arbitrator
     let _register_result = contract
-        .register_arbitrator(250_000_000_000_000_000_000, &alice)
+        .register_arbitrator(250_000_000_000_000_000_000, &alice, None)
         .await;
 
     // 2. Check arbitrator info
     let arbitrator = contract
-        .get_arbitrator(AccountKeyring::Alice.to_account_id().into())
+        .get_arbitrator(&AccountKeyring::Alice.to_account_id().into())
         .await;
     assert!(arbitrator.is_ok());
 
@@ -474,8 +471,9 @@ async fn test_complete_arbitration_workflow() {
         defendant: bob.to_account_id().into(),
         description: "Payment dispute".to_string(),
         evidence_uri: "ipfs://dispute-evidence".to_string(),
+        initial_evidence: None,
     };
 
-    let dispute_result = contract.create_dispute(params, &alice).await;
+    let dispute_result = contract.create_dispute(params, &alice, None).await;
     assert!(dispute_result.is_ok());
 }